@@ -779,6 +779,19 @@ pub static FUNC_MANUAL: Lazy<HashMap<&str, CompiledInterpolation>> = Lazy::new(|
 	"cv_Mat_at_const_const_intX" => include_str!("../tpl/settings/rust_mat_at_const.tpl.rs").compile_interpolation(),
 });
 
+/// dict of functions that first call into Rust and only call OpenCV if the Rust function returns `None`
+/// key: Func.identifier()
+/// value: path to the Rust function taking the same arguments as the generated one and returning `Option<Result<_>>`
+pub static FUNC_INTERCEPT: Lazy<HashMap<&str, &str>> = Lazy::new(|| hashmap! {
+	// ### highgui ###, routed to the Recorder when it's installed
+	"cv_destroyAllWindows" => "crate::manual::highgui::intercept::destroy_all_windows",
+	"cv_destroyWindow_const_StringR" => "crate::manual::highgui::intercept::destroy_window",
+	"cv_imshow_const_StringR_const__InputArrayR" => "crate::manual::highgui::intercept::imshow",
+	"cv_namedWindow_const_StringR_int" => "crate::manual::highgui::intercept::named_window",
+	"cv_waitKeyEx_int" => "crate::manual::highgui::intercept::wait_key_ex",
+	"cv_waitKey_int" => "crate::manual::highgui::intercept::wait_key",
+});
+
 pub static FUNC_SPECIALIZE: Lazy<HashMap<&str, Vec<HashMap<&str, &str>>>> = Lazy::new(|| hashmap! {
 	"cv_dnn_Dict_set_const_StringR_const_TR" => vec![
 		hashmap! { "const T" => "cv::String" },
@@ -982,6 +995,38 @@ pub static NO_SKIP_NAMESPACE_IN_LOCALNAME: Lazy<HashMap<&str, HashMap<&str, &str
 	}
 });
 
+/// Thread-safety markers emitted for the generated boxed classes, `Vector`s and `Ptr`s
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThreadSafety {
+	/// Neither `Send` nor `Sync`, the object is bound to the thread that created it or points into another object
+	NotSend,
+	/// `Send` only, the object can be moved to another thread, but can't be shared between threads
+	Send,
+	/// Both `Send` and `Sync`, the const methods of the object are safe to call concurrently
+	SendSync,
+}
+
+/// Thread-safety of the classes not mentioned in `THREAD_SAFETY`
+///
+/// Most of OpenCV objects can be moved between threads, but a lot of them mutate internal state (caches, buffers) even
+/// from the const methods so `Sync` must be opted into explicitly.
+pub const THREAD_SAFETY_DEFAULT: ThreadSafety = ThreadSafety::Send;
+
+/// cpp_fullname => thread-safety of the class, applies also to the `Vector`s of that class
+///
+/// `Ptr`s share the object so they are only `Send` and `Sync` if the class is `SendSync`, otherwise they are neither.
+/// `Vector`s and `Ptr`s of primitives, strings and simple classes are always `Send` and `Sync`.
+pub static THREAD_SAFETY: Lazy<HashMap<&str, ThreadSafety>> = Lazy::new(|| hashmap! {
+	"cv::Mat" => ThreadSafety::Send, // not Sync, roi() and shallow copies created from &Mat can write to the shared data
+	"cv::Range" => ThreadSafety::SendSync,
+	"cv::MatConstIterator" => ThreadSafety::NotSend, // points into the data of the Mat
+	"cv::SparseMatConstIterator" => ThreadSafety::NotSend, // points into the data of the SparseMat
+	"cv::SparseMatIterator" => ThreadSafety::NotSend, // points into the data of the SparseMat
+	"cv::SparseMat::Node" => ThreadSafety::NotSend, // points into the data of the SparseMat
+	"cv::UMatData" => ThreadSafety::NotSend, // owned and locked by the UMat allocator
+	"cv::ocl::Queue" => ThreadSafety::NotSend, // default queue is thread-local
+});
+
 pub static PREVENT_VECTOR_TYPEDEF_GENERATION: Lazy<HashSet<&str>> = Lazy::new(|| hashset! {
 	"cv::ppf_match_3d::Pose3DPtr",
 	"cv::dnn::Net::LayerId",
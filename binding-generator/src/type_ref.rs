@@ -678,6 +678,34 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 		}
 	}
 
+	/// Thread-safety markers of the generated Rust type, see `settings::THREAD_SAFETY`
+	pub fn thread_safety(&self) -> settings::ThreadSafety {
+		if self.is_copy() || self.as_string().is_some() {
+			return settings::ThreadSafety::SendSync;
+		}
+		match self.canonical().kind() {
+			Kind::StdVector(vec) => {
+				vec.element_type().thread_safety()
+			},
+			Kind::SmartPtr(ptr) => {
+				// Ptr shares the object like `Arc` (e.g. the one returned from a getter shares it with its owner) so it can
+				// only be moved to another thread if the object can be used from several threads at once
+				match ptr.pointee().thread_safety() {
+					settings::ThreadSafety::SendSync => settings::ThreadSafety::SendSync,
+					settings::ThreadSafety::Send | settings::ThreadSafety::NotSend => settings::ThreadSafety::NotSend,
+				}
+			},
+			Kind::Class(cls) => {
+				settings::THREAD_SAFETY.get(cls.cpp_fullname().as_ref())
+					.copied()
+					.unwrap_or(settings::THREAD_SAFETY_DEFAULT)
+			},
+			_ => {
+				settings::ThreadSafety::NotSend
+			}
+		}
+	}
+
 	pub fn is_nullable(&self) -> bool {
 		matches!(self.type_hint, TypeRefTypeHint::NullableSlice)
 	}
@@ -17,7 +17,7 @@ use crate::{
 	StrExt,
 };
 
-use super::RustNativeGeneratedElement;
+use super::{rust_thread_safety_markers, RustNativeGeneratedElement};
 
 fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 	static BOXED_TPL: Lazy<CompiledInterpolation> = Lazy::new(
@@ -210,6 +210,8 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			"".to_string()
 		};
 
+		let thread_safety = rust_thread_safety_markers(&type_ref, &rust_local);
+
 		out += &tpl.interpolate(&hashmap! {
 			"doc_comment" => Cow::Owned(c.rendered_doc_comment(opencv_version)),
 			"debug" => get_debug(c).into(),
@@ -225,6 +227,7 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 				"methods" => inherent_methods.into(),
 			}).into(),
 			"impls" => impls.into(),
+			"thread_safety" => thread_safety.into(),
 		});
 	}
 	out
@@ -120,6 +120,10 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 	if let Some(attrs) = settings::FUNC_CFG_ATTR.get(identifier.as_ref()) {
 		attributes = format!("#[cfg({})]", attrs.0);
 	}
+	let mut intercept = String::new();
+	if let Some(path) = settings::FUNC_INTERCEPT.get(identifier.as_ref()) {
+		intercept = format!("if let Some(out) = {path}({args}) {{ return out; }}", path = path, args = forward_args);
+	}
 
 	let tpl = if let Some(tpl) = settings::FUNC_MANUAL.get(identifier.as_ref()) {
 		tpl
@@ -130,6 +134,7 @@ fn gen_rust_with_name(f: &Func, name: &str, opencv_version: &str) -> String {
 		"doc_comment" => doc_comment.as_str(),
 		"debug" => &debug,
 		"attributes" => &attributes,
+		"intercept" => &intercept,
 		"visibility" => &visibility,
 		"unsafety_decl" => if is_safe { "" } else { "unsafe " },
 		"name" => name,
@@ -24,9 +24,10 @@ use crate::{
 	is_ephemeral_header,
 	IteratorExt,
 	opencv_module_from_path,
-	settings,
+	settings::{self, ThreadSafety},
 	StrExt,
 	Typedef,
+	TypeRef,
 };
 
 mod abstract_ref_wrapper;
@@ -212,6 +213,23 @@ fn join<T: AsRef<str>>(v: &mut Vec<(String, T)>) -> String {
 		.join("")
 }
 
+/// Generates `unsafe impl Send/Sync` for the type according to its `settings::ThreadSafety`
+///
+/// The markers start with an empty line so that the templates don't leave the stray empty lines when there are none.
+fn rust_thread_safety_markers(type_ref: &TypeRef, rust_full: &str) -> String {
+	match type_ref.thread_safety() {
+		ThreadSafety::NotSend => {
+			"".to_string()
+		}
+		ThreadSafety::Send => {
+			format!("\nunsafe impl Send for {} {{}}", rust_full)
+		}
+		ThreadSafety::SendSync => {
+			format!("\nunsafe impl Send for {0} {{}}\nunsafe impl Sync for {0} {{}}", rust_full)
+		}
+	}
+}
+
 impl Drop for RustNativeBindingWriter<'_> {
 	fn drop(&mut self) {
 		static RUST: Lazy<CompiledInterpolation> = Lazy::new(||
@@ -12,7 +12,7 @@ use crate::{
 	type_ref,
 };
 
-use super::RustNativeGeneratedElement;
+use super::{rust_thread_safety_markers, RustNativeGeneratedElement};
 
 impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 	fn element_safe_id(&self) -> String {
@@ -76,6 +76,7 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 			inter_vars.insert("ctor", "".into());
 		}
 		inter_vars.insert("impls", impls.into());
+		inter_vars.insert("thread_safety", rust_thread_safety_markers(&type_ref, &self.rust_fullname()).into());
 		TPL.interpolate(&inter_vars)
 	}
 
@@ -9,6 +9,7 @@ impl {{rust_localalias}} {
 	#[inline] pub fn as_raw_{{rust_localalias}}(&self) -> {{rust_extern_const}} { self.as_raw() }
 	#[inline] pub fn as_raw_mut_{{rust_localalias}}(&mut self) -> {{rust_extern_mut}} { self.as_raw_mut() }
 }
+{{thread_safety}}
 
 {{impls}}
 
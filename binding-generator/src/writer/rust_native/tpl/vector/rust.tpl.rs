@@ -9,8 +9,7 @@ vector_extern! { {{inner_rust_full}}, {{rust_extern_const}}, {{rust_extern_mut}}
 	cv_{{rust_localalias}}_push, cv_{{rust_localalias}}_insert,
 }
 {{additional_methods}}
-
-unsafe impl Send for {{rust_full}} {}
+{{thread_safety}}
 {{impls}}
 
 
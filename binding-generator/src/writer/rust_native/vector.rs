@@ -13,7 +13,7 @@ use crate::{
 	Vector,
 };
 
-use super::RustNativeGeneratedElement;
+use super::{rust_thread_safety_markers, RustNativeGeneratedElement};
 
 impl RustNativeGeneratedElement for Vector<'_, '_> {
 	fn element_safe_id(&self) -> String {
@@ -80,6 +80,7 @@ impl RustNativeGeneratedElement for Vector<'_, '_> {
 
 			inter_vars.insert("additional_methods", additional_methods.into());
 			inter_vars.insert("impls", impls.into());
+			inter_vars.insert("thread_safety", rust_thread_safety_markers(&vec_type, &self.rust_fullname()).into());
 			&COMMON_TPL
 		}.interpolate(&inter_vars)
 	}
@@ -34,6 +34,10 @@ static HOST_TRIPLE: Lazy<Option<String>> = Lazy::new(|| env::var("HOST_TRIPLE").
 static OPENCV_BRANCH_32: Lazy<VersionReq> = Lazy::new(|| VersionReq::parse("~3.2").expect("Can't parse OpenCV 3.2 version requirement"));
 static OPENCV_BRANCH_34: Lazy<VersionReq> = Lazy::new(|| VersionReq::parse("~3.4").expect("Can't parse OpenCV 3.4 version requirement"));
 static OPENCV_BRANCH_4: Lazy<VersionReq> = Lazy::new(|| VersionReq::parse("~4").expect("Can't parse OpenCV 4 version requirement"));
+/// `UsacParams` and the USAC overloads of the RANSAC functions in calib3d
+static OPENCV_HAS_USAC: Lazy<VersionReq> = Lazy::new(|| VersionReq::parse(">=4.5.1").expect("Can't parse OpenCV USAC version requirement"));
+/// `imcount()` or `ImageCollection` used by `imgcodecs::pages()`
+static OPENCV_HAS_IMAGE_PAGES: Lazy<VersionReq> = Lazy::new(|| VersionReq::parse(">=4.5.5").expect("Can't parse OpenCV image pages version requirement"));
 
 static ENV_VARS: [&str; 16] = [
 	"OPENCV_PACKAGE_NAME",
@@ -247,6 +251,7 @@ fn build_wrapper(opencv: &Library) {
 fn main() -> Result<()> {
 	if cfg!(feature = "docs-only") { // fake setup for docs.rs
 		println!(r#"cargo:rustc-cfg=ocvrs_opencv_branch_4"#);
+		println!(r#"cargo:rustc-cfg=ocvrs_has_usac"#);
 		for entry in SRC_DIR.join("opencv/hub").read_dir().expect("Can't read hub dir") {
 			let entry = entry.expect("Can't read directory entry");
 			let path = entry.path();
@@ -309,6 +314,12 @@ fn main() -> Result<()> {
 	} else {
 		panic!("Unsupported OpenCV version: {}, must be from 3.2, 3.4 or 4.x branch", opencv.version);
 	}
+	if OPENCV_HAS_USAC.matches(&opencv.version) {
+		println!("cargo:rustc-cfg=ocvrs_has_usac");
+	}
+	if OPENCV_HAS_IMAGE_PAGES.matches(&opencv.version) {
+		println!("cargo:rustc-cfg=ocvrs_has_image_pages");
+	}
 	let opencv_header_dir = opencv.include_paths.iter()
 		.find(|p| get_version_header(p).is_some())
 		.expect("Discovered OpenCV include paths is empty or contains non-existent paths");
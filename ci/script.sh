@@ -54,6 +54,7 @@ cargo test -vv
 cargo test --release -vv
 
 cargo test --release -vv --features clang-runtime
+cargo test --release -vv --features futures-core,highgui-recording,image,ndarray,rayon,serde
 pushd ci/test-proj-clang-runtime
 cargo run -vv
 popd
@@ -1,29 +1,154 @@
 use std::{
 	ffi::NulError,
 	fmt,
+	panic::Location,
 };
 
 use crate::core;
 
+macro_rules! error_kind {
+	($($code: ident),+ $(,)?) => {
+		/// Kind of the error, corresponds to the `cv::Error::Code` values
+		///
+		/// Use `Error::kind()` to get it. New variants can be added in the future versions of OpenCV so the enum is
+		/// non-exhaustive, the codes that are not known to this crate are reported as `Unknown`.
+		#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+		#[non_exhaustive]
+		pub enum ErrorKind {
+			$( $code, )+
+			Unknown(i32),
+		}
+
+		impl ErrorKind {
+			/// Numeric code of the error, the same as the value of corresponding `core::*` constant
+			pub fn code(self) -> i32 {
+				match self {
+					$( Self::$code => core::$code, )+
+					Self::Unknown(code) => code,
+				}
+			}
+		}
+
+		impl From<i32> for ErrorKind {
+			fn from(code: i32) -> Self {
+				match code {
+					$( core::$code => Self::$code, )+
+					_ => Self::Unknown(code),
+				}
+			}
+		}
+	};
+}
+
+error_kind!(
+	StsOk,
+	StsBackTrace,
+	StsError,
+	StsInternal,
+	StsNoMem,
+	StsBadArg,
+	StsBadFunc,
+	StsNoConv,
+	StsAutoTrace,
+	BadImageSize,
+	BadOffset,
+	BadDataPtr,
+	BadStep,
+	BadModelOrChSeq,
+	BadNumChannels,
+	BadNumChannel1U,
+	BadDepth,
+	BadAlphaChannel,
+	BadOrder,
+	BadOrigin,
+	BadAlign,
+	BadCallBack,
+	BadTileSize,
+	BadCOI,
+	BadROISize,
+	StsNullPtr,
+	StsVecLengthErr,
+	StsFilterStructContentErr,
+	StsKernelStructContentErr,
+	StsFilterOffsetErr,
+	StsBadSize,
+	StsDivByZero,
+	StsInplaceNotSupported,
+	StsObjectNotFound,
+	StsUnmatchedFormats,
+	StsBadFlag,
+	StsBadPoint,
+	StsBadMask,
+	StsUnmatchedSizes,
+	StsUnsupportedFormat,
+	StsOutOfRange,
+	StsParseError,
+	StsNotImplemented,
+	StsBadMemBlock,
+	StsAssert,
+	GpuNotSupported,
+	GpuApiCallError,
+	OpenGlNotSupported,
+	OpenGlApiCallError,
+	OpenCLApiCallError,
+	OpenCLDoubleNotSupported,
+	OpenCLInitError,
+	OpenCLNoAMDBlasFft,
+);
+
+/// Location in the OpenCV C++ code where the `cv::Exception` was thrown
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CppLocation {
+	pub func: String,
+	pub file: String,
+	pub line: i32,
+}
+
+impl fmt::Display for CppLocation {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.func.is_empty() {
+			write!(f, "{}:{}", self.file, self.line)
+		} else {
+			write!(f, "{} ({}:{})", self.func, self.file, self.line)
+		}
+	}
+}
+
 #[derive(Debug)]
 pub struct Error {
 	pub code: i32,
 	pub message: String,
+	/// Where the error was thrown on the C++ side, only available for the errors originating from `cv::Exception`
+	pub cpp_location: Option<CppLocation>,
+	/// Location in the Rust code of the failed OpenCV call or the place where the error was created
+	pub rust_location: &'static Location<'static>,
 }
 
 impl Error {
+	#[track_caller]
 	pub fn new(code: i32, message: String) -> Self {
-		Self { code, message }
+		Self { code, message, cpp_location: None, rust_location: Location::caller() }
+	}
+
+	/// Returns the typed kind of this error, prefer matching on it instead of comparing `code` with constants
+	#[inline]
+	pub fn kind(&self) -> ErrorKind {
+		ErrorKind::from(self.code)
 	}
 }
 
 impl fmt::Display for Error {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "{} (code: {})", self.message, self.code)
+		write!(f, "{} (code: {})", self.message, self.code)?;
+		if let Some(cpp_location) = &self.cpp_location {
+			write!(f, " in {}", cpp_location)?;
+		}
+		write!(f, " called from {}", self.rust_location)
 	}
 }
 
 impl From<NulError> for Error {
+	#[track_caller]
 	fn from(_: NulError) -> Self {
 		Self::new(core::StsBadArg, "Passed Rust string contains nul byte".into())
 	}
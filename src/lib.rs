@@ -1,6 +1,6 @@
 #![allow(broken_intra_doc_links)]
 
-pub use error::{Error, Result};
+pub use error::{CppLocation, Error, ErrorKind, Result};
 
 pub use crate::opencv::hub::*;
 
@@ -0,0 +1,9 @@
+pub use calibration::*;
+pub use pose::*;
+pub use session::*;
+pub use undistort::*;
+
+mod calibration;
+mod pose;
+mod session;
+mod undistort;
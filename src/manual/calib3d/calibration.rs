@@ -0,0 +1,324 @@
+use std::convert::TryFrom;
+
+use crate::{
+	calib3d,
+	core::{self, Mat, Matx33d, Point2f, Point3f, Size, TermCriteria, Vec3d, Vector},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Lens distortion coefficients together with the model they belong to
+///
+/// The coefficient order is the same as in OpenCV: `k1, k2, p1, p2[, k3[, k4, k5, k6[, s1, s2, s3, s4[, tau_x, tau_y]]]]`
+/// for the Brown-Conrady models of `calib3d` and `k1, k2, k3, k4` for the fisheye model.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DistortionModel {
+	Brown5([f64; 5]),
+	/// With `CALIB_RATIONAL_MODEL`
+	Brown8([f64; 8]),
+	/// With `CALIB_THIN_PRISM_MODEL`
+	Brown12([f64; 12]),
+	/// With `CALIB_TILTED_MODEL`
+	Brown14([f64; 14]),
+	Fisheye([f64; 4]),
+}
+
+impl DistortionModel {
+	/// Creates the Brown-Conrady model from 4, 5, 8, 12 or 14 coefficients, 4 coefficients are extended with zero `k3`
+	pub fn from_brown_coeffs(coeffs: &[f64]) -> Result<Self> {
+		fn copy<const N: usize>(coeffs: &[f64]) -> [f64; N] {
+			let mut out = [0.; N];
+			out[..coeffs.len()].copy_from_slice(coeffs);
+			out
+		}
+
+		match coeffs.len() {
+			4 | 5 => Ok(Self::Brown5(copy(coeffs))),
+			8 => Ok(Self::Brown8(copy(coeffs))),
+			12 => Ok(Self::Brown12(copy(coeffs))),
+			14 => Ok(Self::Brown14(copy(coeffs))),
+			len => Err(Error::new(core::StsBadArg, format!("Brown distortion model must have 4, 5, 8, 12 or 14 coefficients, but got: {}", len))),
+		}
+	}
+
+	pub fn from_fisheye_coeffs(coeffs: &[f64]) -> Result<Self> {
+		<[f64; 4]>::try_from(coeffs)
+			.map(Self::Fisheye)
+			.map_err(|_| Error::new(core::StsBadArg, format!("Fisheye distortion model must have 4 coefficients, but got: {}", coeffs.len())))
+	}
+
+	/// Reads the coefficients from a `Mat` as returned by the calibration functions
+	pub fn from_mat(coeffs: &Mat, fisheye: bool) -> Result<Self> {
+		let mut coeffs_f64 = Mat::default();
+		coeffs.convert_to(&mut coeffs_f64, f64::typ(), 1., 0.)?;
+		let coeffs = coeffs_f64.data_typed::<f64>()?;
+		if fisheye {
+			Self::from_fisheye_coeffs(coeffs)
+		} else {
+			Self::from_brown_coeffs(coeffs)
+		}
+	}
+
+	#[inline]
+	pub fn coeffs(&self) -> &[f64] {
+		match self {
+			Self::Brown5(coeffs) => coeffs,
+			Self::Brown8(coeffs) => coeffs,
+			Self::Brown12(coeffs) => coeffs,
+			Self::Brown14(coeffs) => coeffs,
+			Self::Fisheye(coeffs) => coeffs,
+		}
+	}
+
+	#[inline]
+	pub fn is_fisheye(&self) -> bool {
+		matches!(self, Self::Fisheye(..))
+	}
+
+	/// Coefficients as a 1xN `CV_64F` `Mat` suitable for the `dist_coeffs` or `d` arguments
+	#[inline]
+	pub fn to_mat(&self) -> Result<Mat> {
+		Mat::from_slice(self.coeffs())
+	}
+}
+
+/// Intrinsic parameters of a single camera
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraIntrinsics {
+	/// `[fx 0 cx; 0 fy cy; 0 0 1]`
+	pub camera_matrix: Matx33d,
+	pub dist_coeffs: DistortionModel,
+	/// Size of the images used for the calibration
+	pub image_size: Size,
+}
+
+/// Position of the second camera relative to the first one
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StereoExtrinsics {
+	pub rotation: Matx33d,
+	pub translation: Vec3d,
+	pub essential: Matx33d,
+	pub fundamental: Matx33d,
+}
+
+/// Result of `CameraIntrinsics::calibrate()` and `CameraIntrinsics::calibrate_fisheye()`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrationResult {
+	pub intrinsics: CameraIntrinsics,
+	/// Overall RMS reprojection error in pixels
+	pub rms: f64,
+	/// RMS reprojection error of every view in pixels
+	pub per_view_errors: Vec<f64>,
+	/// Rotation of the calibration target in every view as a Rodrigues vector
+	pub rvecs: Vec<Vec3d>,
+	/// Translation of the calibration target in every view
+	pub tvecs: Vec<Vec3d>,
+}
+
+/// Result of `StereoExtrinsics::calibrate()`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StereoCalibrationResult {
+	pub first: CameraIntrinsics,
+	pub second: CameraIntrinsics,
+	pub extrinsics: StereoExtrinsics,
+	/// Overall RMS reprojection error in pixels
+	pub rms: f64,
+	/// RMS reprojection error of every view in pixels for the first and the second camera
+	pub per_view_errors: Vec<[f64; 2]>,
+}
+
+fn check_views(object_points: &Vector<Vector<Point3f>>, image_points: &Vector<Vector<Point2f>>) -> Result<()> {
+	if object_points.is_empty() {
+		return Err(Error::new(core::StsBadArg, "At least one view is required for the calibration".to_string()));
+	}
+	if object_points.len() != image_points.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Number of views with object points: {} doesn't match the number of views with image points: {}", object_points.len(), image_points.len())));
+	}
+	Ok(())
+}
+
+/// Reads the `Nx1 CV_64FC3` output produced for `rvecs` and `tvecs` when they are passed as `Mat`
+fn vecs_from_mat(vecs: &Mat) -> Result<Vec<Vec3d>> {
+	vecs.data_typed::<Vec3d>().map(|vecs| vecs.to_vec())
+}
+
+/// RMS distance between the detected and the projected points of every view
+fn per_view_errors(
+	object_points: &Vector<Vector<Point3f>>,
+	image_points: &Vector<Vector<Point2f>>,
+	rvecs: &[Vec3d],
+	tvecs: &[Vec3d],
+	mut project: impl FnMut(&Vector<Point3f>, &Mat, &Mat, &mut Vector<Point2f>) -> Result<()>,
+) -> Result<Vec<f64>> {
+	let mut projected = Vector::new();
+	object_points.iter()
+		.zip(image_points.iter())
+		.zip(rvecs.iter().zip(tvecs))
+		.map(|((object, image), (rvec, tvec))| {
+			project(&object, &Mat::from_slice(&rvec.0)?, &Mat::from_slice(&tvec.0)?, &mut projected)?;
+			let sum_sq = image.iter()
+				.zip(projected.iter())
+				.map(|(detected, projected)| {
+					let (dx, dy) = (f64::from(detected.x - projected.x), f64::from(detected.y - projected.y));
+					dx * dx + dy * dy
+				})
+				.sum::<f64>();
+			Ok((sum_sq / image.len().max(1) as f64).sqrt())
+		})
+		.collect()
+}
+
+impl CameraIntrinsics {
+	#[inline]
+	pub fn fx(&self) -> f64 {
+		self.camera_matrix[(0, 0)]
+	}
+
+	#[inline]
+	pub fn fy(&self) -> f64 {
+		self.camera_matrix[(1, 1)]
+	}
+
+	#[inline]
+	pub fn cx(&self) -> f64 {
+		self.camera_matrix[(0, 2)]
+	}
+
+	#[inline]
+	pub fn cy(&self) -> f64 {
+		self.camera_matrix[(1, 2)]
+	}
+
+	/// Calibrates a pinhole camera with `calibrate_camera()`
+	///
+	/// The number of the distortion coefficients is selected by `flags`: 5 by default, 8 with `CALIB_RATIONAL_MODEL`,
+	/// 12 with `CALIB_THIN_PRISM_MODEL` and 14 with `CALIB_TILTED_MODEL`.
+	pub fn calibrate(object_points: &Vector<Vector<Point3f>>, image_points: &Vector<Vector<Point2f>>, image_size: Size, flags: i32, criteria: TermCriteria) -> Result<CalibrationResult> {
+		check_views(object_points, image_points)?;
+		let mut camera_matrix = Mat::default();
+		let mut dist_coeffs = Mat::default();
+		let mut rvecs = Mat::default();
+		let mut tvecs = Mat::default();
+		let rms = calib3d::calibrate_camera(object_points, image_points, image_size, &mut camera_matrix, &mut dist_coeffs, &mut rvecs, &mut tvecs, flags, criteria)?;
+		let intrinsics = Self {
+			camera_matrix: Matx33d::try_from(&camera_matrix)?,
+			dist_coeffs: DistortionModel::from_mat(&dist_coeffs, false)?,
+			image_size,
+		};
+		let rvecs = vecs_from_mat(&rvecs)?;
+		let tvecs = vecs_from_mat(&tvecs)?;
+		let per_view_errors = per_view_errors(object_points, image_points, &rvecs, &tvecs, |object, rvec, tvec, projected| {
+			calib3d::project_points(object, rvec, tvec, &camera_matrix, &dist_coeffs, projected, &mut core::no_array()?, 0.)
+		})?;
+		Ok(CalibrationResult { intrinsics, rms, per_view_errors, rvecs, tvecs })
+	}
+
+	/// Calibrates a fisheye camera with `fisheye::calibrate()` (`calib3d::calibrate()` in this crate)
+	///
+	/// `flags` are the `Fisheye_CALIB_*` constants.
+	pub fn calibrate_fisheye(object_points: &Vector<Vector<Point3f>>, image_points: &Vector<Vector<Point2f>>, image_size: Size, flags: i32, criteria: TermCriteria) -> Result<CalibrationResult> {
+		check_views(object_points, image_points)?;
+		let mut camera_matrix = Mat::default();
+		let mut dist_coeffs = Mat::default();
+		let mut rvecs = Mat::default();
+		let mut tvecs = Mat::default();
+		let rms = calib3d::calibrate(object_points, image_points, image_size, &mut camera_matrix, &mut dist_coeffs, &mut rvecs, &mut tvecs, flags, criteria)?;
+		let intrinsics = Self {
+			camera_matrix: Matx33d::try_from(&camera_matrix)?,
+			dist_coeffs: DistortionModel::from_mat(&dist_coeffs, true)?,
+			image_size,
+		};
+		let rvecs = vecs_from_mat(&rvecs)?;
+		let tvecs = vecs_from_mat(&tvecs)?;
+		let per_view_errors = per_view_errors(object_points, image_points, &rvecs, &tvecs, |object, rvec, tvec, projected| {
+			calib3d::fisheye_project_points_vec(object, projected, rvec, tvec, &camera_matrix, &dist_coeffs, 0., &mut core::no_array()?)
+		})?;
+		Ok(CalibrationResult { intrinsics, rms, per_view_errors, rvecs, tvecs })
+	}
+}
+
+impl StereoExtrinsics {
+	/// Calibrates a stereo pair with `stereo_calibrate()` starting from the intrinsics of both cameras
+	///
+	/// With `CALIB_FIX_INTRINSIC` in `flags` only the extrinsics are estimated, otherwise the intrinsics are refined too.
+	/// Both cameras must use Brown-Conrady distortion models and have the same image size.
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	pub fn calibrate(
+		object_points: &Vector<Vector<Point3f>>,
+		image_points1: &Vector<Vector<Point2f>>,
+		image_points2: &Vector<Vector<Point2f>>,
+		first: &CameraIntrinsics,
+		second: &CameraIntrinsics,
+		flags: i32,
+		criteria: TermCriteria,
+	) -> Result<StereoCalibrationResult> {
+		check_views(object_points, image_points1)?;
+		check_views(object_points, image_points2)?;
+		if first.dist_coeffs.is_fisheye() || second.dist_coeffs.is_fisheye() {
+			return Err(Error::new(core::StsBadArg, "Stereo calibration requires Brown distortion models, use fisheye_stereo_calibrate() for fisheye cameras".to_string()));
+		}
+		// stereo_calibrate() takes a single image size for both cameras
+		if first.image_size != second.image_size {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Image size of the first camera: {:?} doesn't match the image size of the second camera: {:?}", first.image_size, second.image_size)));
+		}
+		let mut camera_matrix1 = first.camera_matrix.to_mat()?;
+		let mut dist_coeffs1 = first.dist_coeffs.to_mat()?;
+		let mut camera_matrix2 = second.camera_matrix.to_mat()?;
+		let mut dist_coeffs2 = second.dist_coeffs.to_mat()?;
+		let mut r = Mat::default();
+		let mut t = Mat::default();
+		let mut e = Mat::default();
+		let mut f = Mat::default();
+		let mut per_view_errors = Mat::default();
+		let rms = calib3d::stereo_calibrate_extended(
+			object_points,
+			image_points1,
+			image_points2,
+			&mut camera_matrix1,
+			&mut dist_coeffs1,
+			&mut camera_matrix2,
+			&mut dist_coeffs2,
+			first.image_size,
+			&mut r,
+			&mut t,
+			&mut e,
+			&mut f,
+			&mut per_view_errors,
+			flags,
+			criteria,
+		)?;
+		let mut t_f64 = Mat::default();
+		t.convert_to(&mut t_f64, f64::typ(), 1., 0.)?;
+		let t = t_f64.data_typed::<f64>()?;
+		let per_view_errors = per_view_errors.data_typed::<f64>()?
+			.chunks_exact(2)
+			.map(|errors| [errors[0], errors[1]])
+			.collect();
+		Ok(StereoCalibrationResult {
+			first: CameraIntrinsics {
+				camera_matrix: Matx33d::try_from(&camera_matrix1)?,
+				dist_coeffs: DistortionModel::from_mat(&dist_coeffs1, false)?,
+				image_size: first.image_size,
+			},
+			second: CameraIntrinsics {
+				camera_matrix: Matx33d::try_from(&camera_matrix2)?,
+				dist_coeffs: DistortionModel::from_mat(&dist_coeffs2, false)?,
+				image_size: second.image_size,
+			},
+			extrinsics: Self {
+				rotation: Matx33d::try_from(&r)?,
+				translation: Vec3d::from([t[0], t[1], t[2]]),
+				essential: Matx33d::try_from(&e)?,
+				fundamental: Matx33d::try_from(&f)?,
+			},
+			rms,
+			per_view_errors,
+		})
+	}
+}
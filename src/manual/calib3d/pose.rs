@@ -0,0 +1,219 @@
+use std::{
+	convert::TryFrom,
+	ffi::c_void,
+};
+
+#[cfg(ocvrs_has_usac)]
+use crate::calib3d::UsacParams;
+use crate::{
+	calib3d,
+	core::{self, Affine3d, Mat, Matx33d, Point2f, Point3f, Vec3d, Vector},
+	Error,
+	prelude::*,
+	Result,
+	sys,
+};
+
+use super::calibration::CameraIntrinsics;
+
+/// Algorithm used by `estimate_pose()`, `flags` are the `SOLVEPNP_*` constants
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PnpMethod {
+	/// `solve_pnp()`, all points are treated as inliers
+	Direct { flags: i32 },
+	/// `solve_pnp_ransac()`, `reprojection_error` is the inlier threshold in pixels
+	Ransac { flags: i32, iterations: i32, reprojection_error: f32, confidence: f64 },
+	/// `solve_pnp_ransac()` using the USAC framework, requires OpenCV 4.5.1
+	#[cfg(ocvrs_has_usac)]
+	Usac(UsacParams),
+	/// `solve_pnp_generic()`, the solution with the lowest reprojection error is used, requires OpenCV 3.4.7 or 4.1.0
+	Generic { flags: i32 },
+}
+
+impl PnpMethod {
+	/// `solve_pnp_ransac()` with the default OpenCV parameters
+	pub fn ransac() -> Self {
+		Self::Ransac { flags: calib3d::SOLVEPNP_ITERATIVE, iterations: 100, reprojection_error: 8., confidence: 0.99 }
+	}
+}
+
+impl Default for PnpMethod {
+	fn default() -> Self {
+		Self::Direct { flags: calib3d::SOLVEPNP_ITERATIVE }
+	}
+}
+
+/// Rigid transformation from the object to the camera coordinate system
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pose {
+	pub rotation: Matx33d,
+	pub translation: Vec3d,
+}
+
+impl Pose {
+	/// Creates the pose from the `rvec` and `tvec` as returned by `solve_pnp()` or `calibrate_camera()`
+	pub fn from_rvec_tvec(rvec: Vec3d, tvec: Vec3d) -> Result<Self> {
+		let mut rotation = Mat::default();
+		calib3d::rodrigues(&Mat::from_slice(&rvec.0)?, &mut rotation, &mut core::no_array()?)?;
+		Ok(Self {
+			rotation: Matx33d::try_from(&rotation)?,
+			translation: tvec,
+		})
+	}
+
+	/// Rotation as a Rodrigues vector
+	pub fn rvec(&self) -> Result<Vec3d> {
+		let mut rvec = Mat::default();
+		calib3d::rodrigues(&self.rotation, &mut rvec, &mut core::no_array()?)?;
+		let rvec = rvec.data_typed::<f64>()?;
+		Ok(Vec3d::from([rvec[0], rvec[1], rvec[2]]))
+	}
+}
+
+impl From<Pose> for Affine3d {
+	#[inline]
+	fn from(pose: Pose) -> Self {
+		Affine3d::new(pose.rotation, pose.translation)
+	}
+}
+
+impl From<Affine3d> for Pose {
+	#[inline]
+	fn from(affine: Affine3d) -> Self {
+		Self { rotation: affine.rotation(), translation: affine.translation() }
+	}
+}
+
+/// Result of `estimate_pose()`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoseEstimate {
+	pub pose: Pose,
+	/// Indices of the points consistent with the pose, all points for the non-RANSAC methods
+	pub inliers: Vec<usize>,
+	/// RMS reprojection error of the inliers in pixels
+	pub rms: f64,
+}
+
+/// Estimates the pose of an object from 3D-2D point correspondences
+///
+/// For the fisheye cameras the image points are undistorted first and the pose is estimated for the pinhole camera with the
+/// same camera matrix. Returns `None` if the pose can't be found.
+pub fn estimate_pose(object_points: &Vector<Point3f>, image_points: &Vector<Point2f>, intrinsics: &CameraIntrinsics, method: PnpMethod) -> Result<Option<PoseEstimate>> {
+	if object_points.len() != image_points.len() {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Number of object points: {} doesn't match the number of image points: {}", object_points.len(), image_points.len())));
+	}
+	let camera_matrix = intrinsics.camera_matrix.to_mat()?;
+	let dist_coeffs = intrinsics.dist_coeffs.to_mat()?;
+	let no_distortion = Mat::default();
+	let mut undistorted = Vector::<Point2f>::new();
+	let (solve_points, solve_dist_coeffs) = if intrinsics.dist_coeffs.is_fisheye() {
+		calib3d::fisheye_undistort_points(image_points, &mut undistorted, &camera_matrix, &dist_coeffs, &core::no_array()?, &camera_matrix)?;
+		(&undistorted, &no_distortion)
+	} else {
+		(image_points, &dist_coeffs)
+	};
+
+	let mut rvec = Mat::default();
+	let mut tvec = Mat::default();
+	let mut inliers = Vector::<i32>::new();
+	let found = match method {
+		PnpMethod::Direct { flags } => {
+			calib3d::solve_pnp(object_points, solve_points, &camera_matrix, solve_dist_coeffs, &mut rvec, &mut tvec, false, flags)?
+		}
+		PnpMethod::Ransac { flags, iterations, reprojection_error, confidence } => {
+			calib3d::solve_pnp_ransac(object_points, solve_points, &camera_matrix, solve_dist_coeffs, &mut rvec, &mut tvec, false, iterations, reprojection_error, confidence, &mut inliers, flags)?
+		}
+		#[cfg(ocvrs_has_usac)]
+		PnpMethod::Usac(params) => {
+			let mut camera_matrix = camera_matrix.try_clone()?;
+			calib3d::solve_pnp_ransac_1(object_points, solve_points, &mut camera_matrix, solve_dist_coeffs, &mut rvec, &mut tvec, &mut inliers, params)?
+		}
+		PnpMethod::Generic { flags } => {
+			extern "C" { fn cv_manual_solvePnPGeneric(object_points: *const c_void, image_points: *const c_void, camera_matrix: *const c_void, dist_coeffs: *const c_void, flags: i32, rvecs: *mut c_void, tvecs: *mut c_void) -> sys::Result<i32>; }
+			let mut rvecs = Mat::default();
+			let mut tvecs = Mat::default();
+			let solutions = {
+				let object_points = object_points.input_array()?;
+				let solve_points = solve_points.input_array()?;
+				let camera_matrix = camera_matrix.input_array()?;
+				let solve_dist_coeffs = solve_dist_coeffs.input_array()?;
+				unsafe {
+					cv_manual_solvePnPGeneric(
+						object_points.as_raw__InputArray(),
+						solve_points.as_raw__InputArray(),
+						camera_matrix.as_raw__InputArray(),
+						solve_dist_coeffs.as_raw__InputArray(),
+						flags,
+						rvecs.as_raw_mut_Mat(),
+						tvecs.as_raw_mut_Mat(),
+					)
+				}.into_result()?
+			};
+			// without solutions the output Mats are left unallocated
+			if solutions <= 0 {
+				return Ok(None);
+			}
+			let mut best = None;
+			for (rvec, tvec) in rvecs.data_typed::<Vec3d>()?.iter().zip(tvecs.data_typed::<Vec3d>()?).take(solutions as usize) {
+				let pose = Pose::from_rvec_tvec(*rvec, *tvec)?;
+				let all = (0..object_points.len()).collect::<Vec<_>>();
+				let rms = reprojection_rms(object_points, image_points, &all, &pose, &camera_matrix, &dist_coeffs, intrinsics.dist_coeffs.is_fisheye())?;
+				if best.as_ref().map_or(true, |best: &PoseEstimate| rms < best.rms) {
+					best = Some(PoseEstimate { pose, inliers: all, rms });
+				}
+			}
+			return Ok(best);
+		}
+	};
+	if !found {
+		return Ok(None);
+	}
+
+	let mut rvec_f64 = Mat::default();
+	rvec.convert_to(&mut rvec_f64, f64::typ(), 1., 0.)?;
+	let mut tvec_f64 = Mat::default();
+	tvec.convert_to(&mut tvec_f64, f64::typ(), 1., 0.)?;
+	let (rvec, tvec) = (rvec_f64.data_typed::<f64>()?, tvec_f64.data_typed::<f64>()?);
+	let pose = Pose::from_rvec_tvec(Vec3d::from([rvec[0], rvec[1], rvec[2]]), Vec3d::from([tvec[0], tvec[1], tvec[2]]))?;
+	let inliers = match method {
+		PnpMethod::Direct { .. } => (0..object_points.len()).collect(),
+		_ => inliers.iter().map(|i| i as usize).collect(),
+	};
+	let rms = reprojection_rms(object_points, image_points, &inliers, &pose, &camera_matrix, &dist_coeffs, intrinsics.dist_coeffs.is_fisheye())?;
+	Ok(Some(PoseEstimate { pose, inliers, rms }))
+}
+
+/// RMS distance between the `image_points` and the projected `object_points` selected by `indices`
+fn reprojection_rms(
+	object_points: &Vector<Point3f>,
+	image_points: &Vector<Point2f>,
+	indices: &[usize],
+	pose: &Pose,
+	camera_matrix: &Mat,
+	dist_coeffs: &Mat,
+	fisheye: bool,
+) -> Result<f64> {
+	if indices.is_empty() {
+		return Ok(0.);
+	}
+	let object = indices.iter().map(|&i| object_points.get(i)).collect::<Result<Vector<_>>>()?;
+	let rvec = Mat::from_slice(&pose.rvec()?.0)?;
+	let tvec = Mat::from_slice(&pose.translation.0)?;
+	let mut projected = Vector::<Point2f>::new();
+	if fisheye {
+		calib3d::fisheye_project_points_vec(&object, &mut projected, &rvec, &tvec, camera_matrix, dist_coeffs, 0., &mut core::no_array()?)?;
+	} else {
+		calib3d::project_points(&object, &rvec, &tvec, camera_matrix, dist_coeffs, &mut projected, &mut core::no_array()?, 0.)?;
+	}
+	let sum_sq = indices.iter()
+		.zip(projected.iter())
+		.map(|(&i, projected)| {
+			let detected = image_points.get(i)?;
+			let (dx, dy) = (f64::from(detected.x - projected.x), f64::from(detected.y - projected.y));
+			Ok(dx * dx + dy * dy)
+		})
+		.sum::<Result<f64>>()?;
+	Ok((sum_sq / indices.len() as f64).sqrt())
+}
@@ -0,0 +1,490 @@
+use std::{
+	cmp::Ordering,
+	convert::TryFrom,
+	ffi::c_void,
+};
+
+#[cfg(ocvrs_has_module_aruco)]
+use crate::{
+	aruco::{self, CharucoBoardTrait},
+	core::Ptr,
+};
+use crate::{
+	calib3d,
+	core::{self, Mat, Matx33d, Point2f, Point3f, Rect, Size, TermCriteria, Vector},
+	Error,
+	imgproc,
+	prelude::*,
+	Result,
+	sys,
+};
+
+use super::calibration::{CalibrationResult, CameraIntrinsics};
+
+/// Calibration pattern detected by `CalibrationSession`
+///
+/// `pattern_size` is the number of inner corners or circles per row and column. `square_size`, `spacing` and the
+/// ChArUco lengths are in the units of the resulting translations.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CalibrationTarget {
+	/// Detected with `find_chessboard_corners()` and refined with `corner_sub_pix()`
+	Chessboard { pattern_size: Size, square_size: f32 },
+	/// Detected with `find_chessboard_corners_sb()`
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	ChessboardSB { pattern_size: Size, square_size: f32 },
+	/// Detected with `find_circles_grid()` and `CALIB_CB_SYMMETRIC_GRID`
+	CirclesGrid { pattern_size: Size, spacing: f32 },
+	/// Detected with `find_circles_grid()` and `CALIB_CB_ASYMMETRIC_GRID`
+	///
+	/// `spacing` is the distance between the neighbouring rows, circles within a row are `2 * spacing` apart.
+	AsymmetricCircles { pattern_size: Size, spacing: f32 },
+	/// Detected with `aruco::detect_markers()` and `aruco::interpolate_corners_charuco()`, partially visible boards are accepted
+	#[cfg(ocvrs_has_module_aruco)]
+	CharucoBoard {
+		/// Number of chessboard squares in X and Y direction
+		squares: Size,
+		square_length: f32,
+		marker_length: f32,
+		dictionary: aruco::PREDEFINED_DICTIONARY_NAME,
+	},
+}
+
+impl CalibrationTarget {
+	fn pattern_size(&self) -> Size {
+		match *self {
+			Self::Chessboard { pattern_size, .. } => pattern_size,
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			Self::ChessboardSB { pattern_size, .. } => pattern_size,
+			Self::CirclesGrid { pattern_size, .. } => pattern_size,
+			Self::AsymmetricCircles { pattern_size, .. } => pattern_size,
+			#[cfg(ocvrs_has_module_aruco)]
+			Self::CharucoBoard { squares, .. } => squares,
+		}
+	}
+
+	/// Points of the grid targets in the order they are returned by the detectors
+	fn grid_points(&self) -> Vector<Point3f> {
+		let (pattern_size, step, asymmetric) = match *self {
+			Self::Chessboard { pattern_size, square_size } => (pattern_size, square_size, false),
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			Self::ChessboardSB { pattern_size, square_size } => (pattern_size, square_size, false),
+			Self::CirclesGrid { pattern_size, spacing } => (pattern_size, spacing, false),
+			Self::AsymmetricCircles { pattern_size, spacing } => (pattern_size, spacing, true),
+			#[cfg(ocvrs_has_module_aruco)]
+			Self::CharucoBoard { .. } => return Vector::new(),
+		};
+		(0..pattern_size.height)
+			.flat_map(|row| (0..pattern_size.width).map(move |col| (row, col)))
+			.map(|(row, col)| {
+				let x = if asymmetric { 2 * col + row % 2 } else { col };
+				Point3f::new(x as f32 * step, row as f32 * step, 0.)
+			})
+			.collect()
+	}
+}
+
+/// Thresholds used by `CalibrationSession`
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalibrationSessionParams {
+	/// Views with fewer detected points are not used, relevant for the partially visible ChArUco boards
+	pub min_points: usize,
+	/// Views whose pose is closer than this to an already accepted view are rejected
+	///
+	/// The pose is compared by the board center and size relative to the image and by the logarithm of the
+	/// perspective foreshortening in both directions.
+	pub min_pose_distance: f64,
+	/// Number of cells in X and Y direction the image is split into to measure the coverage
+	pub coverage_grid: Size,
+	/// Views with the reprojection error above `outlier_factor` times the median error are dropped by `finish()`
+	pub outlier_factor: f64,
+	/// Views with the reprojection error below this number of pixels are never dropped
+	pub min_outlier_error: f64,
+	/// Minimum number of views required by `finish()`, outliers are not dropped below it
+	pub min_views: usize,
+}
+
+impl Default for CalibrationSessionParams {
+	fn default() -> Self {
+		Self {
+			min_points: 6,
+			min_pose_distance: 0.05,
+			coverage_grid: Size::new(8, 6),
+			outlier_factor: 2.,
+			min_outlier_error: 0.5,
+			min_views: 3,
+		}
+	}
+}
+
+/// What `CalibrationSession` did with a frame
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FrameOutcome {
+	/// The view was stored under the index `view`
+	Accepted { view: usize, points: usize },
+	/// The target wasn't found or the detected points don't define the board plane
+	NotFound,
+	/// Fewer than `CalibrationSessionParams::min_points` were detected
+	TooFewPoints(usize),
+	/// The pose is too close to the pose of the already accepted `view`
+	TooSimilar { view: usize },
+}
+
+/// Result of `CalibrationSession::finish()`
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionResult {
+	/// Calibration using the views in `views`, `per_view_errors`, `rvecs` and `tvecs` are in the same order
+	pub calibration: CalibrationResult,
+	/// Indices of the views used for the calibration
+	pub views: Vec<usize>,
+	/// Indices of the views dropped because of the large reprojection error
+	pub outliers: Vec<usize>,
+}
+
+#[cfg(ocvrs_has_module_aruco)]
+struct CharucoDetector {
+	dictionary: Ptr<aruco::Dictionary>,
+	board: Ptr<aruco::CharucoBoard>,
+	parameters: Ptr<aruco::DetectorParameters>,
+}
+
+/// Collects the views of a calibration target and calibrates a camera from them
+///
+/// Every frame passed to `add_frame()` is searched for the target, the detection is kept only if it differs enough
+/// from the already accepted views. `coverage()` and `uncovered_cells()` tell which parts of the image still need
+/// views. `finish()` calibrates the camera dropping the views with outlying reprojection errors.
+pub struct CalibrationSession {
+	target: CalibrationTarget,
+	image_size: Size,
+	params: CalibrationSessionParams,
+	/// All points of the target, for ChArUco boards indexed by the corner id
+	target_points: Vector<Point3f>,
+	#[cfg(ocvrs_has_module_aruco)]
+	charuco: Option<CharucoDetector>,
+	object_points: Vector<Vector<Point3f>>,
+	image_points: Vector<Vector<Point2f>>,
+	poses: Vec<[f64; 5]>,
+	covered: Vec<bool>,
+}
+
+impl CalibrationSession {
+	pub fn new(target: CalibrationTarget, image_size: Size, params: CalibrationSessionParams) -> Result<Self> {
+		let pattern_size = target.pattern_size();
+		if pattern_size.width <= 0 || pattern_size.height <= 0 {
+			return Err(Error::new(core::StsBadArg, format!("Invalid pattern size: {:?}", pattern_size)));
+		}
+		if image_size.width <= 0 || image_size.height <= 0 {
+			return Err(Error::new(core::StsBadArg, format!("Invalid image size: {:?}", image_size)));
+		}
+		if params.coverage_grid.width <= 0 || params.coverage_grid.height <= 0 {
+			return Err(Error::new(core::StsBadArg, format!("Invalid coverage grid: {:?}", params.coverage_grid)));
+		}
+		#[cfg(ocvrs_has_module_aruco)]
+		let (target_points, charuco) = if let CalibrationTarget::CharucoBoard { squares, square_length, marker_length, dictionary } = target {
+			let dictionary = aruco::get_predefined_dictionary(dictionary)?;
+			let mut board = aruco::CharucoBoard::create(squares.width, squares.height, square_length, marker_length, &dictionary)?;
+			let target_points = board.chessboard_corners();
+			(target_points, Some(CharucoDetector { dictionary, board, parameters: aruco::DetectorParameters::create()? }))
+		} else {
+			(target.grid_points(), None)
+		};
+		#[cfg(not(ocvrs_has_module_aruco))]
+		let target_points = target.grid_points();
+		Ok(Self {
+			target,
+			image_size,
+			params,
+			target_points,
+			#[cfg(ocvrs_has_module_aruco)]
+			charuco,
+			object_points: Vector::new(),
+			image_points: Vector::new(),
+			poses: vec![],
+			covered: vec![false; (params.coverage_grid.width * params.coverage_grid.height) as usize],
+		})
+	}
+
+	#[inline]
+	pub fn target(&self) -> &CalibrationTarget {
+		&self.target
+	}
+
+	#[inline]
+	pub fn image_size(&self) -> Size {
+		self.image_size
+	}
+
+	/// Number of the accepted views
+	#[inline]
+	pub fn view_count(&self) -> usize {
+		self.poses.len()
+	}
+
+	/// Object points of the accepted views
+	#[inline]
+	pub fn object_points(&self) -> &Vector<Vector<Point3f>> {
+		&self.object_points
+	}
+
+	/// Image points of the accepted views
+	#[inline]
+	pub fn image_points(&self) -> &Vector<Vector<Point2f>> {
+		&self.image_points
+	}
+
+	/// Fraction of the coverage grid cells that contain at least one detected point
+	pub fn coverage(&self) -> f64 {
+		self.covered.iter().filter(|&&covered| covered).count() as f64 / self.covered.len() as f64
+	}
+
+	/// Image regions of the coverage grid cells that contain no detected points yet
+	pub fn uncovered_cells(&self) -> Vec<Rect> {
+		let grid = self.params.coverage_grid;
+		self.covered.iter()
+			.enumerate()
+			.filter(|(_, &covered)| !covered)
+			.map(|(i, _)| {
+				let (col, row) = (i as i32 % grid.width, i as i32 / grid.width);
+				let x = col * self.image_size.width / grid.width;
+				let y = row * self.image_size.height / grid.height;
+				Rect::new(
+					x,
+					y,
+					(col + 1) * self.image_size.width / grid.width - x,
+					(row + 1) * self.image_size.height / grid.height - y,
+				)
+			})
+			.collect()
+	}
+
+	/// Detects the target in `image` and stores the view if it's useful for the calibration
+	pub fn add_frame(&mut self, image: &Mat) -> Result<FrameOutcome> {
+		let size = image.size()?;
+		if size != self.image_size {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Frame size: {:?} doesn't match the session image size: {:?}", size, self.image_size)));
+		}
+		match self.detect(image)? {
+			Some((image_points, object_points)) => self.add_points(image_points, object_points),
+			None => Ok(FrameOutcome::NotFound),
+		}
+	}
+
+	/// Stores a view detected outside of the session, the same checks as in `add_frame()` are applied
+	pub fn add_points(&mut self, image_points: Vector<Point2f>, object_points: Vector<Point3f>) -> Result<FrameOutcome> {
+		if image_points.len() != object_points.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Number of image points: {} doesn't match the number of object points: {}", image_points.len(), object_points.len())));
+		}
+		if image_points.len() < self.params.min_points.max(4) {
+			return Ok(FrameOutcome::TooFewPoints(image_points.len()));
+		}
+		let pose = match self.view_pose(&image_points, &object_points)? {
+			Some(pose) => pose,
+			None => return Ok(FrameOutcome::NotFound),
+		};
+		let closest = self.poses.iter()
+			.map(|other| pose.iter().zip(other).map(|(a, b)| (a - b) * (a - b)).sum::<f64>().sqrt())
+			.enumerate()
+			.min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+		if let Some((view, distance)) = closest {
+			if distance < self.params.min_pose_distance {
+				return Ok(FrameOutcome::TooSimilar { view });
+			}
+		}
+		let grid = self.params.coverage_grid;
+		for point in image_points.iter() {
+			let col = (point.x / self.image_size.width as f32 * grid.width as f32) as i32;
+			let row = (point.y / self.image_size.height as f32 * grid.height as f32) as i32;
+			if (0..grid.width).contains(&col) && (0..grid.height).contains(&row) {
+				self.covered[(row * grid.width + col) as usize] = true;
+			}
+		}
+		let points = image_points.len();
+		self.image_points.push(image_points);
+		self.object_points.push(object_points);
+		self.poses.push(pose);
+		Ok(FrameOutcome::Accepted { view: self.poses.len() - 1, points })
+	}
+
+	/// Calibrates a pinhole camera from the accepted views, see `CameraIntrinsics::calibrate()`
+	pub fn finish(&self, flags: i32, criteria: TermCriteria) -> Result<SessionResult> {
+		self.finish_with(|object_points, image_points| CameraIntrinsics::calibrate(object_points, image_points, self.image_size, flags, criteria))
+	}
+
+	/// Calibrates a fisheye camera from the accepted views, see `CameraIntrinsics::calibrate_fisheye()`
+	pub fn finish_fisheye(&self, flags: i32, criteria: TermCriteria) -> Result<SessionResult> {
+		self.finish_with(|object_points, image_points| CameraIntrinsics::calibrate_fisheye(object_points, image_points, self.image_size, flags, criteria))
+	}
+
+	/// Repeats the calibration dropping the view with the largest error while it's an outlier
+	fn finish_with(&self, mut calibrate: impl FnMut(&Vector<Vector<Point3f>>, &Vector<Vector<Point2f>>) -> Result<CalibrationResult>) -> Result<SessionResult> {
+		let min_views = self.params.min_views.max(1);
+		if self.view_count() < min_views {
+			return Err(Error::new(core::StsBadArg, format!("At least {} views are required for the calibration, but got: {}", min_views, self.view_count())));
+		}
+		let mut views = (0..self.view_count()).collect::<Vec<_>>();
+		let mut outliers = vec![];
+		loop {
+			let object_points = views.iter().map(|&i| self.object_points.get(i)).collect::<Result<Vector<_>>>()?;
+			let image_points = views.iter().map(|&i| self.image_points.get(i)).collect::<Result<Vector<_>>>()?;
+			let calibration = calibrate(&object_points, &image_points)?;
+			if views.len() > min_views {
+				let mut sorted = calibration.per_view_errors.clone();
+				sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+				let threshold = (sorted[sorted.len() / 2] * self.params.outlier_factor).max(self.params.min_outlier_error);
+				let worst = calibration.per_view_errors.iter()
+					.enumerate()
+					.max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+				if let Some((worst, &error)) = worst {
+					if error > threshold {
+						outliers.push(views.remove(worst));
+						continue;
+					}
+				}
+			}
+			outliers.sort_unstable();
+			return Ok(SessionResult { calibration, views, outliers });
+		}
+	}
+
+	fn detect(&self, image: &Mat) -> Result<Option<(Vector<Point2f>, Vector<Point3f>)>> {
+		let mut corners = Vector::<Point2f>::new();
+		let found = match self.target {
+			CalibrationTarget::Chessboard { pattern_size, .. } => {
+				let gray = to_gray(image)?;
+				let found = calib3d::find_chessboard_corners(&gray, pattern_size, &mut corners, calib3d::CALIB_CB_ADAPTIVE_THRESH + calib3d::CALIB_CB_NORMALIZE_IMAGE + calib3d::CALIB_CB_FAST_CHECK)?;
+				if found {
+					let criteria = TermCriteria::new(core::TermCriteria_Type::COUNT as i32 + core::TermCriteria_Type::EPS as i32, 30, 0.001)?;
+					imgproc::corner_sub_pix(&gray, &mut corners, Size::new(5, 5), Size::new(-1, -1), criteria)?;
+				}
+				found
+			}
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			CalibrationTarget::ChessboardSB { pattern_size, .. } => {
+				calib3d::find_chessboard_corners_sb(image, pattern_size, &mut corners, calib3d::CALIB_CB_EXHAUSTIVE + calib3d::CALIB_CB_ACCURACY)?
+			}
+			CalibrationTarget::CirclesGrid { pattern_size, .. } => {
+				find_circles_grid(image, pattern_size, &mut corners, calib3d::CALIB_CB_SYMMETRIC_GRID)?
+			}
+			CalibrationTarget::AsymmetricCircles { pattern_size, .. } => {
+				find_circles_grid(image, pattern_size, &mut corners, calib3d::CALIB_CB_ASYMMETRIC_GRID)?
+			}
+			#[cfg(ocvrs_has_module_aruco)]
+			CalibrationTarget::CharucoBoard { .. } => {
+				return self.detect_charuco(image);
+			}
+		};
+		Ok(if found {
+			Some((corners, self.target_points.clone()))
+		} else {
+			None
+		})
+	}
+
+	#[cfg(ocvrs_has_module_aruco)]
+	fn detect_charuco(&self, image: &Mat) -> Result<Option<(Vector<Point2f>, Vector<Point3f>)>> {
+		let detector = match &self.charuco {
+			Some(detector) => detector,
+			None => return Ok(None),
+		};
+		let mut marker_corners = Vector::<Vector<Point2f>>::new();
+		let mut marker_ids = Vector::<i32>::new();
+		aruco::detect_markers(
+			image,
+			&detector.dictionary,
+			&mut marker_corners,
+			&mut marker_ids,
+			&detector.parameters,
+			&mut core::no_array()?,
+			&core::no_array()?,
+			&core::no_array()?,
+		)?;
+		if marker_ids.is_empty() {
+			return Ok(None);
+		}
+		let mut corners = Vector::<Point2f>::new();
+		let mut ids = Vector::<i32>::new();
+		aruco::interpolate_corners_charuco(
+			&marker_corners,
+			&marker_ids,
+			image,
+			&detector.board,
+			&mut corners,
+			&mut ids,
+			&core::no_array()?,
+			&core::no_array()?,
+			2,
+		)?;
+		if ids.is_empty() {
+			return Ok(None);
+		}
+		let object_points = ids.iter().map(|id| self.target_points.get(id as usize)).collect::<Result<Vector<_>>>()?;
+		Ok(Some((corners, object_points)))
+	}
+
+	/// Board center and size relative to the image and the perspective foreshortening in X and Y direction
+	///
+	/// The bounding rectangle of the detected points on the board plane is projected into the image with the
+	/// homography estimated from the view, `None` is returned if it can't be estimated.
+	fn view_pose(&self, image_points: &Vector<Point2f>, object_points: &Vector<Point3f>) -> Result<Option<[f64; 5]>> {
+		let plane = object_points.iter().map(|p| Point2f::new(p.x, p.y)).collect::<Vector<_>>();
+		let homography = calib3d::find_homography(&plane, image_points, &mut core::no_array()?, 0, 3.)?;
+		if homography.empty()? {
+			return Ok(None);
+		}
+		let h = Matx33d::try_from(&homography)?;
+		let project = |x: f32, y: f32| {
+			let (x, y) = (f64::from(x), f64::from(y));
+			let w = h[(2, 0)] * x + h[(2, 1)] * y + h[(2, 2)];
+			((h[(0, 0)] * x + h[(0, 1)] * y + h[(0, 2)]) / w, (h[(1, 0)] * x + h[(1, 1)] * y + h[(1, 2)]) / w)
+		};
+		let (min_x, min_y, max_x, max_y) = plane.iter()
+			.fold((f32::MAX, f32::MAX, f32::MIN, f32::MIN), |(min_x, min_y, max_x, max_y), p| {
+				(min_x.min(p.x), min_y.min(p.y), max_x.max(p.x), max_y.max(p.y))
+			});
+		if min_x >= max_x || min_y >= max_y {
+			return Ok(None);
+		}
+		let quad = [project(min_x, min_y), project(max_x, min_y), project(max_x, max_y), project(min_x, max_y)];
+		let distance = |a: (f64, f64), b: (f64, f64)| (a.0 - b.0).hypot(a.1 - b.1);
+		let (width, height) = (f64::from(self.image_size.width), f64::from(self.image_size.height));
+		let center_x = quad.iter().map(|p| p.0).sum::<f64>() / 4. / width;
+		let center_y = quad.iter().map(|p| p.1).sum::<f64>() / 4. / height;
+		let area = (0..4)
+			.map(|i| {
+				let (a, b) = (quad[i], quad[(i + 1) % 4]);
+				a.0 * b.1 - b.0 * a.1
+			})
+			.sum::<f64>()
+			.abs() / 2.;
+		let size = (area / (width * height)).sqrt();
+		let tilt_x = (distance(quad[1], quad[2]) / distance(quad[0], quad[3])).ln();
+		let tilt_y = (distance(quad[2], quad[3]) / distance(quad[0], quad[1])).ln();
+		let pose = [center_x, center_y, size, tilt_x, tilt_y];
+		Ok(if pose.iter().all(|x| x.is_finite()) {
+			Some(pose)
+		} else {
+			None
+		})
+	}
+}
+
+fn to_gray(image: &Mat) -> Result<Mat> {
+	let code = match image.channels()? {
+		1 => return image.try_clone(),
+		3 => imgproc::COLOR_BGR2GRAY,
+		4 => imgproc::COLOR_BGRA2GRAY,
+		channels => return Err(Error::new(core::StsBadArg, format!("Unsupported number of image channels: {}", channels))),
+	};
+	let mut gray = Mat::default();
+	imgproc::cvt_color(image, &mut gray, code, 0)?;
+	Ok(gray)
+}
+
+/// `find_circles_grid()` with the default blob detector, the generated overloads differ between OpenCV versions
+fn find_circles_grid(image: &Mat, pattern_size: Size, centers: &mut Vector<Point2f>, flags: i32) -> Result<bool> {
+	extern "C" { fn cv_manual_findCirclesGrid(image: *const c_void, pattern_size: *const Size, centers: *const c_void, flags: i32) -> sys::Result<bool>; }
+	let image = image.input_array()?;
+	let centers = centers.output_array()?;
+	unsafe { cv_manual_findCirclesGrid(image.as_raw__InputArray(), &pattern_size, centers.as_raw__OutputArray(), flags) }.into_result()
+}
@@ -0,0 +1,162 @@
+use std::convert::TryFrom;
+
+use crate::{
+	calib3d,
+	core::{self, Mat, Matx33d, Point2f, Point3f, Rect, Scalar, Size, ToInputArray, ToOutputArray, Vector},
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+use super::calibration::CameraIntrinsics;
+
+/// Undistortion and rectification maps computed once and reused for every frame
+///
+/// `undistort_image()` only calls `remap()`, which is much faster than `undistort()` recomputing the maps on every call.
+/// `undistort_points()` and `distort_points()` map between the pixels of the source and of the undistorted image
+/// for both pinhole and fisheye distortion models.
+pub struct Undistorter {
+	intrinsics: CameraIntrinsics,
+	rectification: Matx33d,
+	new_camera_matrix: Matx33d,
+	output_size: Size,
+	camera_matrix: Mat,
+	dist_coeffs: Mat,
+	map1: Mat,
+	map2: Mat,
+	/// `rectification^T * new_camera_matrix^-1`, maps the undistorted pixels to the camera rays
+	unproject: Matx33d,
+}
+
+impl Undistorter {
+	/// Maps from `intrinsics` to the camera with `new_camera_matrix` and an image of `output_size`
+	///
+	/// With `fixed_point` the maps are stored as `CV_16SC2` instead of `CV_32FC1`, they take less memory and are faster
+	/// to apply at the cost of the precision.
+	pub fn new(intrinsics: &CameraIntrinsics, new_camera_matrix: Matx33d, output_size: Size, fixed_point: bool) -> Result<Self> {
+		Self::new_rectify(intrinsics, Matx33d::eye(), new_camera_matrix, output_size, fixed_point)
+	}
+
+	/// Same as `new()`, but the optimal new camera matrix is calculated from `alpha`
+	///
+	/// `alpha` is passed to `get_optimal_new_camera_matrix()` for the pinhole cameras or as `balance` to
+	/// `fisheye::estimateNewCameraMatrixForUndistortRectify()` for the fisheye ones, 0 keeps only the valid pixels
+	/// and 1 keeps all the source pixels.
+	pub fn with_alpha(intrinsics: &CameraIntrinsics, alpha: f64, fixed_point: bool) -> Result<Self> {
+		let camera_matrix = intrinsics.camera_matrix.to_mat()?;
+		let dist_coeffs = intrinsics.dist_coeffs.to_mat()?;
+		let new_camera_matrix = if intrinsics.dist_coeffs.is_fisheye() {
+			let mut new_camera_matrix = Mat::default();
+			calib3d::estimate_new_camera_matrix_for_undistort_rectify(&camera_matrix, &dist_coeffs, intrinsics.image_size, &Matx33d::eye(), &mut new_camera_matrix, alpha, intrinsics.image_size, 1.)?;
+			new_camera_matrix
+		} else {
+			calib3d::get_optimal_new_camera_matrix(&camera_matrix, &dist_coeffs, intrinsics.image_size, alpha, intrinsics.image_size, &mut Rect::default(), false)?
+		};
+		Self::new(intrinsics, Matx33d::try_from(&new_camera_matrix)?, intrinsics.image_size, fixed_point)
+	}
+
+	/// Maps that additionally apply the `rectification` rotation, e.g. `r1` or `r2` from `stereo_rectify()`
+	pub fn new_rectify(intrinsics: &CameraIntrinsics, rectification: Matx33d, new_camera_matrix: Matx33d, output_size: Size, fixed_point: bool) -> Result<Self> {
+		let camera_matrix = intrinsics.camera_matrix.to_mat()?;
+		let dist_coeffs = intrinsics.dist_coeffs.to_mat()?;
+		let m1type = if fixed_point { core::CV_16SC2 } else { core::CV_32FC1 };
+		let mut map1 = Mat::default();
+		let mut map2 = Mat::default();
+		if intrinsics.dist_coeffs.is_fisheye() {
+			calib3d::fisheye_init_undistort_rectify_map(&camera_matrix, &dist_coeffs, &rectification, &new_camera_matrix, output_size, m1type, &mut map1, &mut map2)?;
+		} else {
+			calib3d::init_undistort_rectify_map(&camera_matrix, &dist_coeffs, &rectification, &new_camera_matrix, output_size, m1type, &mut map1, &mut map2)?;
+		}
+		let unproject = rectification.t() * new_camera_matrix.inv(core::DECOMP_LU)?;
+		Ok(Self {
+			intrinsics: *intrinsics,
+			rectification,
+			new_camera_matrix,
+			output_size,
+			camera_matrix,
+			dist_coeffs,
+			map1,
+			map2,
+			unproject,
+		})
+	}
+
+	#[inline]
+	pub fn intrinsics(&self) -> &CameraIntrinsics {
+		&self.intrinsics
+	}
+
+	#[inline]
+	pub fn rectification(&self) -> Matx33d {
+		self.rectification
+	}
+
+	/// Camera matrix of the undistorted image
+	#[inline]
+	pub fn new_camera_matrix(&self) -> Matx33d {
+		self.new_camera_matrix
+	}
+
+	/// Size of the undistorted image
+	#[inline]
+	pub fn output_size(&self) -> Size {
+		self.output_size
+	}
+
+	/// Maps suitable for `remap()`
+	#[inline]
+	pub fn maps(&self) -> (&Mat, &Mat) {
+		(&self.map1, &self.map2)
+	}
+
+	/// Undistorts the image with `remap()` using `INTER_LINEAR` and black border
+	pub fn undistort_image(&self, src: &dyn ToInputArray, dst: &mut dyn ToOutputArray) -> Result<()> {
+		self.undistort_image_with(src, dst, imgproc::INTER_LINEAR, core::BORDER_CONSTANT, Scalar::default())
+	}
+
+	/// Undistorts the image with `remap()` using the specified `interpolation` and border
+	pub fn undistort_image_with(&self, src: &dyn ToInputArray, dst: &mut dyn ToOutputArray, interpolation: i32, border_mode: i32, border_value: Scalar) -> Result<()> {
+		imgproc::remap(src, dst, &self.map1, &self.map2, interpolation, border_mode, border_value)
+	}
+
+	/// Maps the pixels of the source image to the pixels of the undistorted image
+	pub fn undistort_points(&self, points: &Vector<Point2f>) -> Result<Vector<Point2f>> {
+		let mut out = Vector::new();
+		if points.is_empty() {
+			return Ok(out);
+		}
+		if self.intrinsics.dist_coeffs.is_fisheye() {
+			calib3d::fisheye_undistort_points(points, &mut out, &self.camera_matrix, &self.dist_coeffs, &self.rectification, &self.new_camera_matrix)?;
+		} else {
+			calib3d::undistort_points(points, &mut out, &self.camera_matrix, &self.dist_coeffs, &self.rectification, &self.new_camera_matrix)?;
+		}
+		Ok(out)
+	}
+
+	/// Maps the pixels of the undistorted image back to the pixels of the source image, inverse of `undistort_points()`
+	pub fn distort_points(&self, points: &Vector<Point2f>) -> Result<Vector<Point2f>> {
+		let mut out = Vector::new();
+		if points.is_empty() {
+			return Ok(out);
+		}
+		let u = &self.unproject;
+		let rays = points.iter().map(|p| {
+			let (x, y) = (f64::from(p.x), f64::from(p.y));
+			[
+				u[(0, 0)] * x + u[(0, 1)] * y + u[(0, 2)],
+				u[(1, 0)] * x + u[(1, 1)] * y + u[(1, 2)],
+				u[(2, 0)] * x + u[(2, 1)] * y + u[(2, 2)],
+			]
+		});
+		if self.intrinsics.dist_coeffs.is_fisheye() {
+			let normalized = rays.map(|[x, y, z]| Point2f::new((x / z) as f32, (y / z) as f32)).collect::<Vector<_>>();
+			// fisheye::distortPoints()
+			calib3d::distort_points(&normalized, &mut out, &self.camera_matrix, &self.dist_coeffs, 0.)?;
+		} else {
+			let rays = rays.map(|[x, y, z]| Point3f::new(x as f32, y as f32, z as f32)).collect::<Vector<_>>();
+			let zero = Mat::from_slice(&[0f64; 3])?;
+			calib3d::project_points(&rays, &zero, &zero, &self.camera_matrix, &self.dist_coeffs, &mut out, &mut core::no_array()?, 0.)?;
+		}
+		Ok(out)
+	}
+}
@@ -9,7 +9,6 @@ pub use point3::*;
 pub use ptr::*;
 pub use rect::*;
 pub use size::*;
-pub use sized::*;
 pub use vec::*;
 pub use vector::*;
 
@@ -38,8 +37,9 @@ mod point3;
 mod point;
 pub(crate) mod ptr;
 mod rect;
+#[cfg(feature = "serde")]
+mod serialize;
 mod size;
-mod sized;
 mod vec;
 mod vector;
 
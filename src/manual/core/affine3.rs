@@ -1,11 +1,19 @@
+use std::ops::Mul;
+
+use num_traits::Float;
+
 use crate::{
-	core::{Matx44, MatxTrait, ValidMatxType},
+	core::{Matx33, Matx44, MatxTrait, Point3_, ValidMatxType, ValidPoint3Type, ValidVecType, Vec3, Vec4},
 	opencv_type_simple_generic,
 };
 
 /// [docs.opencv.org](https://docs.opencv.org/master/dd/d99/classcv_1_1Affine3.html)
+///
+/// The transformation is stored as a 4x4 matrix `[R t; 0 1]`, all the operations are implemented in Rust without calling
+/// into OpenCV. For the rigid transformations `R` is a rotation, the rotation conversions expect that.
 #[repr(C)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Affine3<T: ValidMatxType> {
 	pub matrix: Matx44<T>,
 }
@@ -16,4 +24,229 @@ impl<T: ValidMatxType> Default for Affine3<T> {
 	}
 }
 
+#[inline]
+fn lit<T: Float>(val: f64) -> T {
+	T::from(val).unwrap_or_else(T::nan)
+}
+
+impl<T: ValidMatxType + ValidVecType + Float> Affine3<T> {
+	#[inline]
+	pub fn identity() -> Self {
+		Self::default()
+	}
+
+	/// Creates the transformation from the linear part (usually a rotation) and the translation
+	pub fn new(rotation: Matx33<T>, translation: Vec3<T>) -> Self {
+		let mut out = Self::default();
+		out.set_rotation(rotation);
+		out.set_translation(translation);
+		out
+	}
+
+	/// Creates the transformation from the rotation as a Rodrigues vector and the translation, like `rvec`, `tvec` pairs
+	/// returned by `solve_pnp()`
+	pub fn from_rvec(rvec: Vec3<T>, translation: Vec3<T>) -> Self {
+		let theta = (rvec[0] * rvec[0] + rvec[1] * rvec[1] + rvec[2] * rvec[2]).sqrt();
+		if theta < T::epsilon() {
+			// first order approximation
+			let rotation = Matx33::from([
+				[T::one(), -rvec[2], rvec[1]],
+				[rvec[2], T::one(), -rvec[0]],
+				[-rvec[1], rvec[0], T::one()],
+			]);
+			return Self::new(rotation, translation);
+		}
+		let (x, y, z) = (rvec[0] / theta, rvec[1] / theta, rvec[2] / theta);
+		let (s, c) = theta.sin_cos();
+		let c1 = T::one() - c;
+		let rotation = Matx33::from([
+			[c + c1 * x * x, c1 * x * y - s * z, c1 * x * z + s * y],
+			[c1 * y * x + s * z, c + c1 * y * y, c1 * y * z - s * x],
+			[c1 * z * x - s * y, c1 * z * y + s * x, c + c1 * z * z],
+		]);
+		Self::new(rotation, translation)
+	}
+
+	/// Creates the transformation from the rotation quaternion in `w, x, y, z` order and the translation
+	///
+	/// The quaternion doesn't need to be normalized.
+	pub fn from_quaternion(quaternion: Vec4<T>, translation: Vec3<T>) -> Self {
+		let norm = quaternion.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+		let (w, x, y, z) = (quaternion[0] / norm, quaternion[1] / norm, quaternion[2] / norm, quaternion[3] / norm);
+		let two = lit::<T>(2.);
+		let rotation = Matx33::from([
+			[T::one() - two * (y * y + z * z), two * (x * y - w * z), two * (x * z + w * y)],
+			[two * (x * y + w * z), T::one() - two * (x * x + z * z), two * (y * z - w * x)],
+			[two * (x * z - w * y), two * (y * z + w * x), T::one() - two * (x * x + y * y)],
+		]);
+		Self::new(rotation, translation)
+	}
+
+	/// Creates the transformation from the Euler angles in radians and the translation
+	///
+	/// The rotation is `Rz(yaw) * Ry(pitch) * Rx(roll)`, i.e. roll around X is applied first, then pitch around Y and
+	/// yaw around Z, all around the fixed axes.
+	pub fn from_euler(roll: T, pitch: T, yaw: T, translation: Vec3<T>) -> Self {
+		let (sr, cr) = roll.sin_cos();
+		let (sp, cp) = pitch.sin_cos();
+		let (sy, cy) = yaw.sin_cos();
+		let rotation = Matx33::from([
+			[cy * cp, cy * sp * sr - sy * cr, cy * sp * cr + sy * sr],
+			[sy * cp, sy * sp * sr + cy * cr, sy * sp * cr - cy * sr],
+			[-sp, cp * sr, cp * cr],
+		]);
+		Self::new(rotation, translation)
+	}
+
+	/// Linear part of the transformation
+	pub fn rotation(&self) -> Matx33<T> {
+		let m = &self.matrix;
+		Matx33::from([
+			[m[(0, 0)], m[(0, 1)], m[(0, 2)]],
+			[m[(1, 0)], m[(1, 1)], m[(1, 2)]],
+			[m[(2, 0)], m[(2, 1)], m[(2, 2)]],
+		])
+	}
+
+	pub fn set_rotation(&mut self, rotation: Matx33<T>) {
+		for row in 0..3 {
+			for col in 0..3 {
+				self.matrix[(row, col)] = rotation[(row, col)];
+			}
+		}
+	}
+
+	#[inline]
+	pub fn translation(&self) -> Vec3<T> {
+		Vec3::from([self.matrix[(0, 3)], self.matrix[(1, 3)], self.matrix[(2, 3)]])
+	}
+
+	#[inline]
+	pub fn set_translation(&mut self, translation: Vec3<T>) {
+		for (row, &val) in translation.iter().enumerate() {
+			self.matrix[(row, 3)] = val;
+		}
+	}
+
+	/// Rotation as a Rodrigues vector
+	pub fn rvec(&self) -> Vec3<T> {
+		let r = self.rotation();
+		let axis = [r[(2, 1)] - r[(1, 2)], r[(0, 2)] - r[(2, 0)], r[(1, 0)] - r[(0, 1)]];
+		let half = lit::<T>(0.5);
+		let s = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt() * half;
+		let c = ((r[(0, 0)] + r[(1, 1)] + r[(2, 2)] - T::one()) * half).max(-T::one()).min(T::one());
+		if s < lit(1e-5) {
+			if c > T::zero() {
+				return Vec3::all(T::zero());
+			}
+			// rotation by pi, R + I = 2 * k * k^T, the column with the largest diagonal element is the most precise
+			let t = |row: usize, col: usize| (r[(row, col)] + if row == col { T::one() } else { T::zero() }) * half;
+			let col = (1..3).fold(0, |max, i| if t(i, i) > t(max, max) { i } else { max });
+			let norm = t(col, col).sqrt();
+			let mut k = [t(0, col) / norm, t(1, col) / norm, t(2, col) / norm];
+			// for the angles slightly below pi the sign of the axis still matters
+			if k[0] * axis[0] + k[1] * axis[1] + k[2] * axis[2] < T::zero() {
+				k = [-k[0], -k[1], -k[2]];
+			}
+			let theta = s.atan2(c);
+			return Vec3::from([k[0] * theta, k[1] * theta, k[2] * theta]);
+		}
+		let scale = s.atan2(c) / (lit::<T>(2.) * s);
+		Vec3::from([axis[0] * scale, axis[1] * scale, axis[2] * scale])
+	}
+
+	/// Rotation as a unit quaternion in `w, x, y, z` order with non-negative `w`
+	pub fn quaternion(&self) -> Vec4<T> {
+		let r = self.rotation();
+		let quarter = lit::<T>(0.25);
+		let trace = r[(0, 0)] + r[(1, 1)] + r[(2, 2)];
+		let (w, x, y, z) = if trace > T::zero() {
+			let s = (trace + T::one()).sqrt() * lit(2.);
+			(quarter * s, (r[(2, 1)] - r[(1, 2)]) / s, (r[(0, 2)] - r[(2, 0)]) / s, (r[(1, 0)] - r[(0, 1)]) / s)
+		} else if r[(0, 0)] > r[(1, 1)] && r[(0, 0)] > r[(2, 2)] {
+			let s = (T::one() + r[(0, 0)] - r[(1, 1)] - r[(2, 2)]).sqrt() * lit(2.);
+			((r[(2, 1)] - r[(1, 2)]) / s, quarter * s, (r[(0, 1)] + r[(1, 0)]) / s, (r[(0, 2)] + r[(2, 0)]) / s)
+		} else if r[(1, 1)] > r[(2, 2)] {
+			let s = (T::one() + r[(1, 1)] - r[(0, 0)] - r[(2, 2)]).sqrt() * lit(2.);
+			((r[(0, 2)] - r[(2, 0)]) / s, (r[(0, 1)] + r[(1, 0)]) / s, quarter * s, (r[(1, 2)] + r[(2, 1)]) / s)
+		} else {
+			let s = (T::one() + r[(2, 2)] - r[(0, 0)] - r[(1, 1)]).sqrt() * lit(2.);
+			((r[(1, 0)] - r[(0, 1)]) / s, (r[(0, 2)] + r[(2, 0)]) / s, (r[(1, 2)] + r[(2, 1)]) / s, quarter * s)
+		};
+		let norm = (w * w + x * x + y * y + z * z).sqrt();
+		let sign = if w < T::zero() { -T::one() } else { T::one() };
+		Vec4::from([w * sign / norm, x * sign / norm, y * sign / norm, z * sign / norm])
+	}
+
+	/// Rotation as `(roll, pitch, yaw)` Euler angles in radians, see `from_euler()`
+	///
+	/// In the gimbal lock (pitch of ±pi/2) the roll is set to 0.
+	pub fn euler(&self) -> (T, T, T) {
+		let r = self.rotation();
+		let pitch = (-r[(2, 0)]).max(-T::one()).min(T::one()).asin();
+		if pitch.cos() > lit(1e-6) {
+			(r[(2, 1)].atan2(r[(2, 2)]), pitch, r[(1, 0)].atan2(r[(0, 0)]))
+		} else {
+			(T::zero(), pitch, (-r[(0, 1)]).atan2(r[(1, 1)]))
+		}
+	}
+
+	/// Inverse transformation, `None` if the linear part is singular
+	pub fn inv(&self) -> Option<Self> {
+		let m = self.rotation();
+		let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| m[(r0, c0)] * m[(r1, c1)] - m[(r0, c1)] * m[(r1, c0)];
+		let det = m[(0, 0)] * cofactor(1, 2, 1, 2) - m[(0, 1)] * cofactor(1, 2, 0, 2) + m[(0, 2)] * cofactor(1, 2, 0, 1);
+		if det.abs() <= T::epsilon() {
+			return None;
+		}
+		let inv = Matx33::from([
+			[cofactor(1, 2, 1, 2) / det, -cofactor(0, 2, 1, 2) / det, cofactor(0, 1, 1, 2) / det],
+			[-cofactor(1, 2, 0, 2) / det, cofactor(0, 2, 0, 2) / det, -cofactor(0, 1, 0, 2) / det],
+			[cofactor(1, 2, 0, 1) / det, -cofactor(0, 2, 0, 1) / det, cofactor(0, 1, 0, 1) / det],
+		]);
+		let t = self.translation();
+		let translation = Vec3::from([
+			-(inv[(0, 0)] * t[0] + inv[(0, 1)] * t[1] + inv[(0, 2)] * t[2]),
+			-(inv[(1, 0)] * t[0] + inv[(1, 1)] * t[1] + inv[(1, 2)] * t[2]),
+			-(inv[(2, 0)] * t[0] + inv[(2, 1)] * t[1] + inv[(2, 2)] * t[2]),
+		]);
+		Some(Self::new(inv, translation))
+	}
+
+	/// Transformation applying `self` first and `other` after it, same as `other * self`
+	#[inline]
+	pub fn concatenate(&self, other: &Self) -> Self {
+		*other * *self
+	}
+
+	/// Applies the transformation to a point
+	pub fn transform_point(&self, pt: Point3_<T>) -> Point3_<T> where T: ValidPoint3Type {
+		let m = &self.matrix;
+		Point3_::new(
+			m[(0, 0)] * pt.x + m[(0, 1)] * pt.y + m[(0, 2)] * pt.z + m[(0, 3)],
+			m[(1, 0)] * pt.x + m[(1, 1)] * pt.y + m[(1, 2)] * pt.z + m[(1, 3)],
+			m[(2, 0)] * pt.x + m[(2, 1)] * pt.y + m[(2, 2)] * pt.z + m[(2, 3)],
+		)
+	}
+}
+
+impl<T: ValidMatxType> Mul for Affine3<T> {
+	type Output = Self;
+
+	/// Composition, `a * b` applies `b` first and `a` after it
+	#[inline]
+	fn mul(self, rhs: Self) -> Self::Output {
+		Self { matrix: self.matrix * rhs.matrix }
+	}
+}
+
+impl<T: ValidMatxType + ValidVecType + ValidPoint3Type + Float> Mul<Point3_<T>> for Affine3<T> {
+	type Output = Point3_<T>;
+
+	#[inline]
+	fn mul(self, rhs: Point3_<T>) -> Self::Output {
+		self.transform_point(rhs)
+	}
+}
+
 opencv_type_simple_generic! { Affine3<ValidMatxType> }
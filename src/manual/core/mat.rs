@@ -6,7 +6,12 @@ use std::{
 	slice,
 };
 
+pub use borrowed::*;
+#[cfg(feature = "image")]
+pub use image_interop::*;
+pub use iter::*;
 pub use mat_::*;
+pub use ops::*;
 
 use crate::{
 	core::{
@@ -31,7 +36,14 @@ use crate::{
 	sys,
 };
 
+mod borrowed;
+#[cfg(feature = "image")]
+mod image_interop;
+mod iter;
 mod mat_;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
+mod ops;
 
 /// This sealed trait is implemented for types that are valid to use as Mat elements
 pub trait DataType: Copy + private::Sealed {
@@ -128,7 +140,7 @@ unsafe fn convert_ptr_mut<T>(r: &mut u8) -> &mut T {
 	&mut *(r as *mut _ as *mut T)
 }
 
-fn match_format<T: DataType>(mat_type: i32) -> Result<()> {
+pub(super) fn match_format<T: DataType>(mat_type: i32) -> Result<()> {
 	let out_type = T::typ();
 	if mat_type == out_type {
 		Ok(())
@@ -141,7 +153,7 @@ fn match_format<T: DataType>(mat_type: i32) -> Result<()> {
 	}
 }
 
-fn match_dims(mat: &(impl MatTrait + ?Sized), dims: usize) -> Result<()> {
+pub(super) fn match_dims(mat: &(impl MatTrait + ?Sized), dims: usize) -> Result<()> {
 	let mat_dims = mat.dims() as usize;
 	if mat_dims == dims {
 		Ok(())
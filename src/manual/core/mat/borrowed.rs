@@ -0,0 +1,340 @@
+use std::{
+	convert::TryFrom,
+	ffi::c_void,
+	fmt,
+	marker::PhantomData,
+	mem,
+};
+
+use crate::{
+	core::{
+		self,
+		_InputArray,
+		_InputOutputArray,
+		_OutputArray,
+		Mat,
+		Point,
+		Scalar,
+		Size,
+		ToInputArray,
+		ToInputOutputArray,
+		ToOutputArray,
+	},
+	Error,
+	prelude::*,
+	Result,
+};
+
+use super::DataType;
+
+/// Number of columns of the single row `Mat` over the slice of `len` elements
+fn slice_cols(len: usize) -> Result<i32> {
+	i32::try_from(len).map_err(|_| Error::new(core::StsOutOfRange, format!("Slice length: {} is too large for a single row Mat", len)))
+}
+
+/// Checks that `len` elements are enough to hold the matrix with the specified dimensions and returns the row
+/// step in bytes
+fn row_step<T>(len: usize, rows: i32, cols: i32, row_stride: usize) -> Result<usize> {
+	if rows < 0 || cols < 0 {
+		return Err(Error::new(core::StsOutOfRange, format!("Invalid Mat dimensions: {}x{}", rows, cols)));
+	}
+	let (rows, cols) = (rows as usize, cols as usize);
+	if row_stride < cols {
+		return Err(Error::new(core::StsOutOfRange, format!("Row stride: {} is less than the number of columns: {}", row_stride, cols)));
+	}
+	let overflow = || Error::new(core::StsOutOfRange, format!("Row stride: {} is too large for {}x{} elements", row_stride, rows, cols));
+	let required = if rows == 0 {
+		0
+	} else {
+		(rows - 1).checked_mul(row_stride)
+			.and_then(|offset| offset.checked_add(cols))
+			.ok_or_else(overflow)?
+	};
+	if len < required {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Slice length: {} is not enough to hold {}x{} elements with row stride: {}, required length: {}", len, rows, cols, row_stride, required)));
+	}
+	row_stride.checked_mul(mem::size_of::<T>()).ok_or_else(overflow)
+}
+
+/// Read accessors of the borrowed views, they are forwarded one by one instead of dereferencing to `Mat` because
+/// shallow copies like `Mat::roi()` or `Mat::row()` would escape the borrow
+macro_rules! borrowed_read_accessors {
+	($typ: ident) => {
+		impl<T> $typ<'_, T> {
+			/// See `Mat::rows()`
+			#[inline]
+			pub fn rows(&self) -> i32 {
+				self.inner.rows()
+			}
+
+			/// See `Mat::cols()`
+			#[inline]
+			pub fn cols(&self) -> i32 {
+				self.inner.cols()
+			}
+
+			/// See `Mat::size()`
+			#[inline]
+			pub fn size(&self) -> Result<Size> {
+				self.inner.size()
+			}
+
+			/// See `Mat::typ()`
+			#[inline]
+			pub fn typ(&self) -> Result<i32> {
+				self.inner.typ()
+			}
+
+			/// See `Mat::depth()`
+			#[inline]
+			pub fn depth(&self) -> Result<i32> {
+				self.inner.depth()
+			}
+
+			/// See `Mat::channels()`
+			#[inline]
+			pub fn channels(&self) -> Result<i32> {
+				self.inner.channels()
+			}
+
+			/// See `Mat::total()`
+			#[inline]
+			pub fn total(&self) -> Result<usize> {
+				self.inner.total()
+			}
+
+			/// See `Mat::empty()`
+			#[inline]
+			pub fn empty(&self) -> Result<bool> {
+				self.inner.empty()
+			}
+
+			/// See `Mat::is_continuous()`
+			#[inline]
+			pub fn is_continuous(&self) -> Result<bool> {
+				self.inner.is_continuous()
+			}
+
+			/// See `Mat::data()`
+			#[inline]
+			pub fn data(&self) -> Result<&u8> {
+				self.inner.data()
+			}
+
+			/// See `Mat::at()`
+			#[inline]
+			pub fn at<D: DataType>(&self, i0: i32) -> Result<&D> {
+				self.inner.at(i0)
+			}
+
+			/// See `Mat::at_2d()`
+			#[inline]
+			pub fn at_2d<D: DataType>(&self, row: i32, col: i32) -> Result<&D> {
+				self.inner.at_2d(row, col)
+			}
+
+			/// See `Mat::at_pt()`
+			#[inline]
+			pub fn at_pt<D: DataType>(&self, pt: Point) -> Result<&D> {
+				self.inner.at_pt(pt)
+			}
+
+			/// See `Mat::at_row()`
+			#[inline]
+			pub fn at_row<D: DataType>(&self, row: i32) -> Result<&[D]> {
+				self.inner.at_row(row)
+			}
+
+			/// See `Mat::data_typed()`
+			#[inline]
+			pub fn data_typed<D: DataType>(&self) -> Result<&[D]> {
+				self.inner.data_typed()
+			}
+
+			/// Deep copy of the data that is not tied to the borrow
+			#[inline]
+			pub fn try_clone(&self) -> Result<Mat> {
+				self.inner.try_clone()
+			}
+		}
+	};
+}
+
+/// Read-only `Mat` header pointing to the data borrowed from a Rust slice
+///
+/// The data is not copied and the borrow is held for as long as `MatRef` lives. It can be passed to any function
+/// taking `&dyn ToInputArray`. It doesn't deref to `Mat` because the shallow copies (e.g. `Mat::roi()`) would not
+/// be tied to the borrow, use `try_clone()` if you need the data as a `Mat` or to outlive the slice.
+pub struct MatRef<'a, T> {
+	inner: Mat,
+	_data: PhantomData<&'a [T]>,
+}
+
+impl<'a, T: DataType> MatRef<'a, T> {
+	/// Creates a single row `Mat` view over the whole slice
+	#[inline]
+	pub fn from_slice(data: &'a [T]) -> Result<Self> {
+		Self::new(data, 1, slice_cols(data.len())?)
+	}
+
+	/// Creates a continuous `rows` x `cols` `Mat` view over the slice
+	#[inline]
+	pub fn new(data: &'a [T], rows: i32, cols: i32) -> Result<Self> {
+		Self::new_with_stride(data, rows, cols, cols.max(0) as usize)
+	}
+
+	/// Creates a `rows` x `cols` `Mat` view over the slice where each row starts `row_stride` elements after the
+	/// start of the previous one
+	pub fn new_with_stride(data: &'a [T], rows: i32, cols: i32, row_stride: usize) -> Result<Self> {
+		let step = row_step::<T>(data.len(), rows, cols, row_stride)?;
+		let inner = unsafe { Mat::new_rows_cols_with_data(rows, cols, T::typ(), data.as_ptr() as *mut c_void, step) }?;
+		Ok(Self { inner, _data: PhantomData })
+	}
+}
+
+borrowed_read_accessors!(MatRef);
+
+impl<T> ToInputArray for MatRef<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl<T> ToInputArray for &MatRef<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl<T> fmt::Debug for MatRef<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
+
+/// Writeable `Mat` header pointing to the data mutably borrowed from a Rust slice
+///
+/// The data is not copied and the mutable borrow is held for as long as `MatMut` lives. It can be passed as an
+/// output array to OpenCV functions to write the results directly into the slice. Note that if the output of
+/// the function has different size or type OpenCV will allocate a new buffer for it and the slice will be left
+/// untouched, so make sure that the dimensions and type match the expected output.
+///
+/// Like `MatRef` it doesn't deref to `Mat`, the writing is done through the output array traits and the element
+/// accessors below.
+pub struct MatMut<'a, T> {
+	inner: Mat,
+	_data: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T: DataType> MatMut<'a, T> {
+	/// Creates a single row `Mat` view over the whole slice
+	#[inline]
+	pub fn from_slice(data: &'a mut [T]) -> Result<Self> {
+		let cols = slice_cols(data.len())?;
+		Self::new(data, 1, cols)
+	}
+
+	/// Creates a continuous `rows` x `cols` `Mat` view over the slice
+	#[inline]
+	pub fn new(data: &'a mut [T], rows: i32, cols: i32) -> Result<Self> {
+		Self::new_with_stride(data, rows, cols, cols.max(0) as usize)
+	}
+
+	/// Creates a `rows` x `cols` `Mat` view over the slice where each row starts `row_stride` elements after the
+	/// start of the previous one
+	pub fn new_with_stride(data: &'a mut [T], rows: i32, cols: i32, row_stride: usize) -> Result<Self> {
+		let step = row_step::<T>(data.len(), rows, cols, row_stride)?;
+		let inner = unsafe { Mat::new_rows_cols_with_data(rows, cols, T::typ(), data.as_mut_ptr() as *mut c_void, step) }?;
+		Ok(Self { inner, _data: PhantomData })
+	}
+}
+
+borrowed_read_accessors!(MatMut);
+
+impl<T> MatMut<'_, T> {
+	/// See `Mat::at_mut()`
+	#[inline]
+	pub fn at_mut<D: DataType>(&mut self, i0: i32) -> Result<&mut D> {
+		self.inner.at_mut(i0)
+	}
+
+	/// See `Mat::at_2d_mut()`
+	#[inline]
+	pub fn at_2d_mut<D: DataType>(&mut self, row: i32, col: i32) -> Result<&mut D> {
+		self.inner.at_2d_mut(row, col)
+	}
+
+	/// See `Mat::at_pt_mut()`
+	#[inline]
+	pub fn at_pt_mut<D: DataType>(&mut self, pt: Point) -> Result<&mut D> {
+		self.inner.at_pt_mut(pt)
+	}
+
+	/// See `Mat::at_row_mut()`
+	#[inline]
+	pub fn at_row_mut<D: DataType>(&mut self, row: i32) -> Result<&mut [D]> {
+		self.inner.at_row_mut(row)
+	}
+
+	/// See `Mat::data_typed_mut()`
+	#[inline]
+	pub fn data_typed_mut<D: DataType>(&mut self) -> Result<&mut [D]> {
+		self.inner.data_typed_mut()
+	}
+
+	/// See `Mat::set()`
+	#[inline]
+	pub fn set(&mut self, s: Scalar) -> Result<()> {
+		self.inner.set(s)
+	}
+}
+
+impl<T> ToInputArray for MatMut<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl<T> ToInputArray for &MatMut<'_, T> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		(*self).input_array()
+	}
+}
+
+impl<T> ToOutputArray for MatMut<'_, T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		self.inner.output_array()
+	}
+}
+
+impl<T> ToOutputArray for &mut MatMut<'_, T> {
+	#[inline]
+	fn output_array(&mut self) -> Result<_OutputArray> {
+		(*self).output_array()
+	}
+}
+
+impl<T> ToInputOutputArray for MatMut<'_, T> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		self.inner.input_output_array()
+	}
+}
+
+impl<T> ToInputOutputArray for &mut MatMut<'_, T> {
+	#[inline]
+	fn input_output_array(&mut self) -> Result<_InputOutputArray> {
+		(*self).input_output_array()
+	}
+}
+
+impl<T> fmt::Debug for MatMut<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
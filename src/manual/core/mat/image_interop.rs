@@ -0,0 +1,172 @@
+use std::{
+	convert::TryFrom,
+	ops::Deref,
+	slice,
+};
+
+use image::{ImageBuffer, Luma, LumaA, Pixel, Rgb, Rgba};
+
+use crate::{
+	core::{self, Mat, Vec2, Vec2b, Vec2f, Vec3, Vec3b, Vec3f, Vec4, Vec4b, Vec4f},
+	Error,
+	prelude::*,
+	Result,
+};
+
+use super::{DataType, match_dims, match_format};
+
+/// Order of the color channels in a `Mat` that is being converted to or from an `image::ImageBuffer`
+///
+/// OpenCV functions (e.g. `imgcodecs::imread` or `imgcodecs::imdecode`) produce images in BGR(A) order while
+/// the `image` crate uses RGB(A), so the conversion needs to know whether the channels must be swapped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChannelOrder {
+	/// Blue, green, red (and alpha), the default order used by OpenCV
+	Bgr,
+	/// Red, green, blue (and alpha), the same order as used by the `image` crate
+	Rgb,
+}
+
+impl Default for ChannelOrder {
+	#[inline]
+	fn default() -> Self {
+		Self::Bgr
+	}
+}
+
+/// This sealed trait is implemented for `image` pixel types that can be converted to and from `Mat` elements
+pub trait ImagePixel: Pixel + private::Sealed + 'static {
+	/// `Mat` element type that corresponds to a single pixel
+	type Element: DataType;
+	/// Whether the pixel contains red and blue channels that are subject to `ChannelOrder` handling
+	const HAS_COLOR: bool;
+}
+
+macro_rules! image_pixel {
+	($pixel: ty, $element: ty, $has_color: expr) => {
+		impl ImagePixel for $pixel {
+			type Element = $element;
+			const HAS_COLOR: bool = $has_color;
+		}
+
+		impl private::Sealed for $pixel {}
+	};
+}
+
+image_pixel!(Luma<u8>, u8, false);
+image_pixel!(LumaA<u8>, Vec2b, false);
+image_pixel!(Rgb<u8>, Vec3b, true);
+image_pixel!(Rgba<u8>, Vec4b, true);
+
+image_pixel!(Luma<u16>, u16, false);
+image_pixel!(LumaA<u16>, Vec2<u16>, false);
+image_pixel!(Rgb<u16>, Vec3<u16>, true);
+image_pixel!(Rgba<u16>, Vec4<u16>, true);
+
+image_pixel!(Luma<f32>, f32, false);
+image_pixel!(LumaA<f32>, Vec2f, false);
+image_pixel!(Rgb<f32>, Vec3f, true);
+image_pixel!(Rgba<f32>, Vec4f, true);
+
+#[inline]
+fn element_channels<P: ImagePixel>(elements: &[P::Element]) -> &[P::Subpixel] {
+	// safe because ImagePixel is sealed and all Element types are repr(C) arrays of Subpixel
+	unsafe { slice::from_raw_parts(elements.as_ptr() as *const P::Subpixel, elements.len() * P::CHANNEL_COUNT as usize) }
+}
+
+#[inline]
+fn element_channels_mut<P: ImagePixel>(elements: &mut [P::Element]) -> &mut [P::Subpixel] {
+	// safe because ImagePixel is sealed and all Element types are repr(C) arrays of Subpixel
+	unsafe { slice::from_raw_parts_mut(elements.as_mut_ptr() as *mut P::Subpixel, elements.len() * P::CHANNEL_COUNT as usize) }
+}
+
+#[inline]
+fn swap_red_blue<P: ImagePixel>(channels: &mut [P::Subpixel], order: ChannelOrder) {
+	if P::HAS_COLOR && order == ChannelOrder::Bgr {
+		channels.chunks_exact_mut(P::CHANNEL_COUNT as usize)
+			.for_each(|pixel| pixel.swap(0, 2));
+	}
+}
+
+impl Mat {
+	/// Creates a new BGR(A) `Mat` from the `image::ImageBuffer` copying its data
+	///
+	/// See `from_image_buffer_with_order()` to keep the RGB(A) channel order.
+	#[inline]
+	pub fn from_image_buffer<P, C>(buf: &ImageBuffer<P, C>) -> Result<Self>
+		where
+			P: ImagePixel,
+			P::Subpixel: 'static,
+			C: Deref<Target=[P::Subpixel]>,
+	{
+		Self::from_image_buffer_with_order(buf, ChannelOrder::Bgr)
+	}
+
+	/// Creates a new `Mat` from the `image::ImageBuffer` copying its data, `order` specifies the channel order
+	/// of the resulting `Mat`
+	pub fn from_image_buffer_with_order<P, C>(buf: &ImageBuffer<P, C>, order: ChannelOrder) -> Result<Self>
+		where
+			P: ImagePixel,
+			P::Subpixel: 'static,
+			C: Deref<Target=[P::Subpixel]>,
+	{
+		let (width, height) = buf.dimensions();
+		let width = i32::try_from(width).map_err(|_| Error::new(core::StsOutOfRange, format!("Image width: {} is too big for Mat", width)))?;
+		let height = i32::try_from(height).map_err(|_| Error::new(core::StsOutOfRange, format!("Image height: {} is too big for Mat", height)))?;
+		let mut out = unsafe { Self::new_rows_cols(height, width, P::Element::typ()) }?;
+		let row_len = width as usize * P::CHANNEL_COUNT as usize;
+		if row_len > 0 {
+			// the container can be longer than the image
+			for (row_n, src) in buf.as_raw().chunks_exact(row_len).take(height as usize).enumerate() {
+				let trg = element_channels_mut::<P>(out.at_row_mut::<P::Element>(row_n as i32)?);
+				trg.copy_from_slice(src);
+				swap_red_blue::<P>(trg, order);
+			}
+		}
+		Ok(out)
+	}
+
+	/// Copies the data of a 2D `Mat` into a new `image::ImageBuffer`, `order` specifies the channel order of this
+	/// `Mat`
+	///
+	/// The `Mat` type must match the `ImagePixel::Element` of the requested pixel type, e.g. `Vec3b` for `Rgb<u8>`
+	/// or `u16` for `Luma<u16>`, no implicit conversion is performed.
+	pub fn to_image_buffer<P>(&self, order: ChannelOrder) -> Result<ImageBuffer<P, Vec<P::Subpixel>>>
+		where
+			P: ImagePixel,
+			P::Subpixel: 'static,
+	{
+		match_format::<P::Element>(self.typ()?)
+			.and_then(|_| match_dims(self, 2))?;
+		let size = self.size()?;
+		let row_len = size.width as usize * P::CHANNEL_COUNT as usize;
+		let mut data = Vec::with_capacity(row_len * size.height as usize);
+		if row_len > 0 {
+			for row_n in 0..size.height {
+				let start = data.len();
+				data.extend_from_slice(element_channels::<P>(self.at_row::<P::Element>(row_n)?));
+				swap_red_blue::<P>(&mut data[start..], order);
+			}
+		}
+		ImageBuffer::from_raw(size.width as u32, size.height as u32, data)
+			.ok_or_else(|| Error::new(core::StsUnmatchedSizes, "Mat data size doesn't match the image dimensions".to_string()))
+	}
+}
+
+impl<P> TryFrom<&Mat> for ImageBuffer<P, Vec<P::Subpixel>>
+	where
+		P: ImagePixel,
+		P::Subpixel: 'static,
+{
+	type Error = Error;
+
+	/// Converts BGR(A) `Mat` to `image::ImageBuffer`, use `Mat::to_image_buffer()` to specify other channel order
+	#[inline]
+	fn try_from(mat: &Mat) -> Result<Self> {
+		mat.to_image_buffer(ChannelOrder::Bgr)
+	}
+}
+
+mod private {
+	pub trait Sealed {}
+}
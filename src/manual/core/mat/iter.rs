@@ -0,0 +1,300 @@
+use std::{
+	iter::FusedIterator,
+	marker::PhantomData,
+	ptr::NonNull,
+	slice,
+};
+
+use crate::{
+	core::Point,
+	prelude::*,
+	Result,
+};
+
+use super::{DataType, Mat_, match_dims};
+
+/// Memory layout of a 2D `Mat` used by the row iterators
+#[derive(Copy, Clone)]
+struct RowLayout {
+	data: NonNull<u8>,
+	rows: usize,
+	cols: usize,
+	step: usize,
+}
+
+impl RowLayout {
+	/// Layout for the read-only access
+	fn new(mat: &(impl MatTrait + ?Sized)) -> Result<Self> {
+		match Self::dims(mat)? {
+			Some((rows, cols, step)) => Ok(Self { data: NonNull::from(mat.data()?), rows, cols, step }),
+			None => Ok(Self::empty()),
+		}
+	}
+
+	/// Layout for the writeable access, the data pointer is derived from the mutable borrow
+	fn new_mut(mat: &mut (impl MatTrait + ?Sized)) -> Result<Self> {
+		match Self::dims(mat)? {
+			Some((rows, cols, step)) => Ok(Self { data: NonNull::from(mat.data_mut()), rows, cols, step }),
+			None => Ok(Self::empty()),
+		}
+	}
+
+	/// Returns `rows`, `cols` and `step` of the allocated non-empty 2D `Mat`
+	fn dims(mat: &(impl MatTrait + ?Sized)) -> Result<Option<(usize, usize, usize)>> {
+		if mat.dims() == 0 || !mat.is_allocated() {
+			return Ok(None);
+		}
+		match_dims(mat, 2)?;
+		let rows = mat.rows() as usize;
+		let cols = mat.cols() as usize;
+		if rows == 0 || cols == 0 {
+			return Ok(None);
+		}
+		Ok(Some((rows, cols, mat.mat_step()[0])))
+	}
+
+	fn empty() -> Self {
+		Self { data: NonNull::dangling(), rows: 0, cols: 0, step: 0 }
+	}
+
+	#[inline(always)]
+	unsafe fn row_ptr<T>(&self, row: usize) -> *mut T {
+		(self.data.as_ptr() as *mut u8).add(row * self.step) as *mut T
+	}
+}
+
+/// Iterator over the rows of a 2D `Mat_`, returned by `Mat_::rows_iter()`
+///
+/// Rows are yielded as slices, `Mat` step is taken into account so it works for non-continuous matrices too.
+pub struct MatRows<'m, T> {
+	layout: RowLayout,
+	front: usize,
+	back: usize,
+	_d: PhantomData<&'m T>,
+}
+
+impl<'m, T> MatRows<'m, T> {
+	fn new(layout: RowLayout) -> Self {
+		Self { layout, front: 0, back: layout.rows, _d: PhantomData }
+	}
+}
+
+impl<'m, T: 'm> Iterator for MatRows<'m, T> {
+	type Item = &'m [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			let out = unsafe { slice::from_raw_parts(self.layout.row_ptr(self.front), self.layout.cols) };
+			self.front += 1;
+			Some(out)
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
+
+impl<'m, T: 'm> DoubleEndedIterator for MatRows<'m, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.back -= 1;
+			Some(unsafe { slice::from_raw_parts(self.layout.row_ptr(self.back), self.layout.cols) })
+		} else {
+			None
+		}
+	}
+}
+
+impl<'m, T: 'm> ExactSizeIterator for MatRows<'m, T> {}
+
+impl<'m, T: 'm> FusedIterator for MatRows<'m, T> {}
+
+/// Iterator over the writeable rows of a 2D `Mat_`, returned by `Mat_::rows_iter_mut()`
+pub struct MatRowsMut<'m, T> {
+	layout: RowLayout,
+	front: usize,
+	back: usize,
+	_d: PhantomData<&'m mut T>,
+}
+
+impl<'m, T> MatRowsMut<'m, T> {
+	fn new(layout: RowLayout) -> Self {
+		Self { layout, front: 0, back: layout.rows, _d: PhantomData }
+	}
+}
+
+impl<'m, T: 'm> Iterator for MatRowsMut<'m, T> {
+	type Item = &'m mut [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			let out = unsafe { slice::from_raw_parts_mut(self.layout.row_ptr(self.front), self.layout.cols) };
+			self.front += 1;
+			Some(out)
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
+
+impl<'m, T: 'm> DoubleEndedIterator for MatRowsMut<'m, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.back -= 1;
+			Some(unsafe { slice::from_raw_parts_mut(self.layout.row_ptr(self.back), self.layout.cols) })
+		} else {
+			None
+		}
+	}
+}
+
+impl<'m, T: 'm> ExactSizeIterator for MatRowsMut<'m, T> {}
+
+impl<'m, T: 'm> FusedIterator for MatRowsMut<'m, T> {}
+
+/// Iterator over the elements of a 2D `Mat_` in row-major order, returned by `Mat_::iter()`
+pub struct MatIter<'m, T> {
+	rows: MatRows<'m, T>,
+	row: slice::Iter<'m, T>,
+}
+
+impl<'m, T: 'm> Iterator for MatIter<'m, T> {
+	type Item = &'m T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(out) = self.row.next() {
+				return Some(out);
+			}
+			self.row = self.rows.next()?.iter();
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.row.len() + self.rows.len() * self.rows.layout.cols;
+		(len, Some(len))
+	}
+}
+
+impl<'m, T: 'm> ExactSizeIterator for MatIter<'m, T> {}
+
+impl<'m, T: 'm> FusedIterator for MatIter<'m, T> {}
+
+/// Iterator over the writeable elements of a 2D `Mat_` in row-major order, returned by `Mat_::iter_mut()`
+pub struct MatIterMut<'m, T> {
+	rows: MatRowsMut<'m, T>,
+	row: slice::IterMut<'m, T>,
+}
+
+impl<'m, T: 'm> Iterator for MatIterMut<'m, T> {
+	type Item = &'m mut T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(out) = self.row.next() {
+				return Some(out);
+			}
+			self.row = self.rows.next()?.iter_mut();
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.row.len() + self.rows.len() * self.rows.layout.cols;
+		(len, Some(len))
+	}
+}
+
+impl<'m, T: 'm> ExactSizeIterator for MatIterMut<'m, T> {}
+
+impl<'m, T: 'm> FusedIterator for MatIterMut<'m, T> {}
+
+/// Iterator over the elements of a 2D `Mat_` along with their positions, returned by `Mat_::indexed_iter()`
+pub struct MatIndexedIter<'m, T> {
+	rows: MatRows<'m, T>,
+	row: slice::Iter<'m, T>,
+	pos: Point,
+}
+
+impl<'m, T: 'm> Iterator for MatIndexedIter<'m, T> {
+	type Item = (Point, &'m T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(out) = self.row.next() {
+				let pos = self.pos;
+				self.pos.x += 1;
+				return Some((pos, out));
+			}
+			self.pos.y = self.rows.front as i32;
+			self.pos.x = 0;
+			self.row = self.rows.next()?.iter();
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.row.len() + self.rows.len() * self.rows.layout.cols;
+		(len, Some(len))
+	}
+}
+
+impl<'m, T: 'm> ExactSizeIterator for MatIndexedIter<'m, T> {}
+
+impl<'m, T: 'm> FusedIterator for MatIndexedIter<'m, T> {}
+
+impl<T: DataType> Mat_<T> {
+	/// Returns an iterator over the rows of a 2D `Mat_`
+	///
+	/// The `Mat_` step is taken into account so this works with non-continuous matrices (e.g. ROIs) too, FFI is
+	/// only called once when the iterator is created.
+	pub fn rows_iter(&self) -> Result<MatRows<T>> {
+		RowLayout::new(self).map(MatRows::new)
+	}
+
+	/// Returns an iterator over the writeable rows of a 2D `Mat_`, see `rows_iter()`
+	pub fn rows_iter_mut(&mut self) -> Result<MatRowsMut<T>> {
+		RowLayout::new_mut(self).map(MatRowsMut::new)
+	}
+
+	/// Returns an iterator over the elements of a 2D `Mat_` in row-major order
+	pub fn iter(&self) -> Result<MatIter<T>> {
+		self.rows_iter().map(|rows| MatIter { rows, row: (&[]).iter() })
+	}
+
+	/// Returns an iterator over the writeable elements of a 2D `Mat_` in row-major order
+	pub fn iter_mut(&mut self) -> Result<MatIterMut<T>> {
+		self.rows_iter_mut().map(|rows| MatIterMut { rows, row: (&mut []).iter_mut() })
+	}
+
+	/// Returns an iterator over the elements of a 2D `Mat_` in row-major order along with their positions
+	pub fn indexed_iter(&self) -> Result<MatIndexedIter<T>> {
+		self.rows_iter().map(|rows| MatIndexedIter { rows, row: (&[]).iter(), pos: Point::default() })
+	}
+
+	/// Returns a parallel iterator over the writeable rows of a 2D `Mat_`
+	#[cfg(feature = "rayon")]
+	pub fn par_rows_iter_mut(&mut self) -> Result<rayon::vec::IntoIter<&mut [T]>> where T: Send {
+		use rayon::iter::IntoParallelIterator;
+		self.rows_iter_mut().map(|rows| rows.collect::<Vec<_>>().into_par_iter())
+	}
+}
@@ -3,16 +3,36 @@ use std::{
 	ffi::c_void,
 	fmt,
 	marker::PhantomData,
+	ops::{Bound, Index, IndexMut, RangeBounds},
 };
 
 use crate::{
-	core::{_InputArray, Mat, MatTrait, ToInputArray},
+	core::{self, _InputArray, Mat, MatTrait, Point, Range, ToInputArray},
 	Error,
 	Result,
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
 };
 
-use super::{DataType, match_dims, match_format, match_is_continuous, match_total, MatTraitManual};
+use super::{DataType, match_dims, match_format, match_indices, match_is_continuous, match_total, MatTraitManual};
+
+/// Converts Rust range into OpenCV `Range` checking that it lies within `0..len`
+fn range_within(range: impl RangeBounds<i32>, len: i32) -> Result<Range> {
+	let start = match range.start_bound() {
+		Bound::Included(&start) => start,
+		Bound::Excluded(&start) => start.saturating_add(1),
+		Bound::Unbounded => 0,
+	};
+	let end = match range.end_bound() {
+		Bound::Included(&end) => end.saturating_add(1),
+		Bound::Excluded(&end) => end,
+		Bound::Unbounded => len,
+	};
+	if 0 <= start && start <= end && end <= len {
+		Range::new(start, end)
+	} else {
+		Err(Error::new(core::StsOutOfRange, format!("Range: {}..{} out of bounds: 0..{}", start, end, len)))
+	}
+}
 
 /// [docs.opencv.org](https://docs.opencv.org/master/df/dfc/classcv_1_1Mat__.html)
 ///
@@ -87,6 +107,70 @@ impl<T: DataType> Mat_<T> {
 		match_is_continuous(self)?;
 		unsafe { self.data_typed_unchecked_mut() }
 	}
+
+	/// Returns a typed sub-matrix of a 2D `Mat_` limited to the specified rows and columns
+	///
+	/// Accepts any Rust ranges, e.g. `m.roi(..2, 1..=3)`. Like `Mat::roi()` the data is not copied so the changes
+	/// to the returned `Mat_` are visible in this one.
+	pub fn roi(&self, rows: impl RangeBounds<i32>, cols: impl RangeBounds<i32>) -> Result<Self> {
+		match_dims(self, 2)?;
+		let rows = range_within(rows, self.rows())?;
+		let cols = range_within(cols, self.cols())?;
+		Mat::rowscols(&self.inner, &rows, &cols)
+			.map(|inner| Self { inner, _type: PhantomData })
+	}
+
+	/// Returns a typed sub-matrix of a 2D `Mat_` containing the specified rows, see `roi()`
+	#[inline]
+	pub fn rows_in(&self, rows: impl RangeBounds<i32>) -> Result<Self> {
+		self.roi(rows, ..)
+	}
+
+	/// Returns a typed sub-matrix of a 2D `Mat_` containing the specified columns, see `roi()`
+	#[inline]
+	pub fn cols_in(&self, cols: impl RangeBounds<i32>) -> Result<Self> {
+		self.roi(.., cols)
+	}
+}
+
+/// Panicking element access of a 2D `Mat_` by `(row, col)`, use `at_2d()` for the fallible version
+impl<T: DataType> Index<(i32, i32)> for Mat_<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, (row, col): (i32, i32)) -> &Self::Output {
+		match match_indices(self, &[row, col]).and_then(|_| unsafe { self.at_2d_unchecked(row, col) }) {
+			Ok(out) => out,
+			Err(e) => panic!("{}", e),
+		}
+	}
+}
+
+impl<T: DataType> IndexMut<(i32, i32)> for Mat_<T> {
+	#[inline]
+	fn index_mut(&mut self, (row, col): (i32, i32)) -> &mut Self::Output {
+		match match_indices(self, &[row, col]).and_then(|_| unsafe { self.at_2d_unchecked_mut(row, col) }) {
+			Ok(out) => out,
+			Err(e) => panic!("{}", e),
+		}
+	}
+}
+
+/// Panicking element access of a 2D `Mat_` by `Point`, use `at_pt()` for the fallible version
+impl<T: DataType> Index<Point> for Mat_<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, pt: Point) -> &Self::Output {
+		&self[(pt.y, pt.x)]
+	}
+}
+
+impl<T: DataType> IndexMut<Point> for Mat_<T> {
+	#[inline]
+	fn index_mut(&mut self, pt: Point) -> &mut Self::Output {
+		&mut self[(pt.y, pt.x)]
+	}
 }
 
 impl<T> MatTrait for Mat_<T> {
@@ -0,0 +1,235 @@
+use std::{
+	convert::TryFrom,
+	mem,
+	slice,
+};
+
+use ndarray::{ArrayBase, ArrayD, ArrayViewD, ArrayViewMutD, Data, Dimension, IxDyn, ShapeBuilder};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+use super::{DataType, Mat_};
+
+fn match_single_channel<T: DataType>() -> Result<()> {
+	if T::channels() == 1 {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsUnmatchedFormats, format!("Requested element type has: {} channels, but only single channel types can be used along with the channels axis", T::channels())))
+	}
+}
+
+fn match_depth<T: DataType>(mat: &(impl MatTrait + ?Sized)) -> Result<()> {
+	match_single_channel::<T>()?;
+	let mat_depth = mat.depth()?;
+	let out_depth = T::depth();
+	if mat_depth == out_depth {
+		Ok(())
+	} else {
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		let mat_depth = core::depth_to_string(mat_depth)?;
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		let out_depth = core::depth_to_string(out_depth)?;
+		Err(Error::new(core::StsUnmatchedFormats, format!("Mat depth is: {}, but requested depth is: {}", mat_depth, out_depth)))
+	}
+}
+
+/// Calculates ndarray shape and strides (in elements) for the `Mat`
+///
+/// `elem_size` is the size of a single ndarray element in bytes, if `channel_axis` is true then an additional
+/// trailing axis is added to the output which spans the channels of a single `Mat` element.
+fn shape_strides(mat: &(impl MatTrait + ?Sized), elem_size: usize, channel_axis: bool) -> Result<(Vec<usize>, Vec<usize>)> {
+	let elem_size1 = mat.elem_size1()?;
+	let mat_size = mat.mat_size();
+	let mut shape = Vec::with_capacity(mat_size.len() + 1);
+	let mut strides = Vec::with_capacity(mat_size.len() + 1);
+	for (i, &dim) in mat_size.iter().enumerate() {
+		let step = mat.step1(i as i32)? * elem_size1;
+		if step % elem_size != 0 {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Mat step: {} along dimension: {} is not a multiple of element size: {}", step, i, elem_size)));
+		}
+		shape.push(dim as usize);
+		strides.push(step / elem_size);
+	}
+	if shape.is_empty() {
+		shape.push(0);
+		strides.push(0);
+	}
+	if channel_axis {
+		shape.push(mat.channels()? as usize);
+		strides.push(1);
+	}
+	Ok((shape, strides))
+}
+
+/// # Safety
+/// Caller must ensure that the element type `T` matches the data stored in the `Mat`
+unsafe fn array_view<T>(mat: &(impl MatTrait + ?Sized), channel_axis: bool) -> Result<ArrayViewD<T>> {
+	let (shape, strides) = shape_strides(mat, mem::size_of::<T>(), channel_axis)?;
+	if shape.iter().product::<usize>() == 0 {
+		return Ok(ArrayViewD::from_shape(IxDyn(&shape), &[]).expect("Empty shape must be valid"));
+	}
+	let data = mat.data()? as *const u8 as *const T;
+	Ok(ArrayViewD::from_shape_ptr(IxDyn(&shape).strides(IxDyn(&strides)), data))
+}
+
+/// # Safety
+/// Caller must ensure that the element type `T` matches the data stored in the `Mat`
+unsafe fn array_view_mut<T>(mat: &mut (impl MatTrait + ?Sized), channel_axis: bool) -> Result<ArrayViewMutD<T>> {
+	let (shape, strides) = shape_strides(mat, mem::size_of::<T>(), channel_axis)?;
+	if shape.iter().product::<usize>() == 0 {
+		return Ok(ArrayViewMutD::from_shape(IxDyn(&shape), &mut []).expect("Empty shape must be valid"));
+	}
+	let data = mat.data_mut() as *mut u8 as *mut T;
+	Ok(ArrayViewMutD::from_shape_ptr(IxDyn(&shape).strides(IxDyn(&strides)), data))
+}
+
+fn from_ndarray<T: DataType, S: Data<Elem=T>, D: Dimension>(arr: &ArrayBase<S, D>, channel_axis: bool) -> Result<Mat> {
+	let mut sizes = arr.shape().iter()
+		.map(|&x| i32::try_from(x).map_err(|_| Error::new(core::StsOutOfRange, format!("Array dimension: {} is too big for Mat", x))))
+		.collect::<Result<Vec<_>>>()?;
+	let typ = if channel_axis {
+		match_single_channel::<T>()?;
+		match sizes.pop() {
+			Some(channels) if 0 < channels && channels <= core::CV_CN_MAX => core::CV_MAKETYPE(T::depth(), channels),
+			Some(channels) => return Err(Error::new(core::StsOutOfRange, format!("Channel count: {} out of bounds 1..={}", channels, core::CV_CN_MAX))),
+			None => return Err(Error::new(core::StsUnmatchedSizes, "Array must have at least one dimension to use it as channels".to_string())),
+		}
+	} else {
+		T::typ()
+	};
+	if sizes.is_empty() {
+		sizes.push(1);
+	}
+	let mut out = Mat::default();
+	unsafe { out.create_nd(&sizes, typ) }?;
+	let len = arr.len();
+	if len > 0 {
+		let trg = unsafe { slice::from_raw_parts_mut(out.data_mut() as *mut u8 as *mut T, len) };
+		if let Some(src) = arr.as_slice() {
+			trg.copy_from_slice(src);
+		} else {
+			trg.iter_mut().zip(arr.iter()).for_each(|(trg, src)| *trg = *src);
+		}
+	}
+	Ok(out)
+}
+
+impl Mat {
+	/// Returns a read-only `ndarray` view into the `Mat` data without copying it
+	///
+	/// The view has one axis per `Mat` dimension plus a trailing axis for channels, so a 2D 3-channel `Mat`
+	/// becomes a view of shape `[rows, cols, 3]`. `T` must be a single channel type matching the `Mat` depth.
+	/// `Mat` steps are honored, so non-continuous submatrices are also viewed in place.
+	pub fn as_array_view<T: DataType>(&self) -> Result<ArrayViewD<T>> {
+		match_depth::<T>(self)
+			.and_then(|_| unsafe { array_view(self, true) })
+	}
+
+	/// Returns a writeable `ndarray` view into the `Mat` data without copying it, see `as_array_view()`
+	pub fn as_array_view_mut<T: DataType>(&mut self) -> Result<ArrayViewMutD<T>> {
+		match_depth::<T>(self)?;
+		unsafe { array_view_mut(self, true) }
+	}
+
+	/// Copies the `Mat` data into an owned `ndarray` array with the same layout as `as_array_view()`
+	pub fn to_array<T: DataType>(&self) -> Result<ArrayD<T>> {
+		self.as_array_view().map(|view| view.to_owned())
+	}
+
+	/// Creates a new `Mat` of type `T` with the shape of `arr` and copies its data
+	pub fn from_array<T: DataType, S: Data<Elem=T>, D: Dimension>(arr: &ArrayBase<S, D>) -> Result<Self> {
+		from_ndarray(arr, false)
+	}
+
+	/// Creates a new `Mat` treating the last axis of `arr` as channels and copies its data
+	///
+	/// This is the reverse operation of `as_array_view()`, e.g. an array of shape `[rows, cols, 3]` becomes a 2D
+	/// 3-channel `Mat`.
+	pub fn from_array_with_channels<T: DataType, S: Data<Elem=T>, D: Dimension>(arr: &ArrayBase<S, D>) -> Result<Self> {
+		from_ndarray(arr, true)
+	}
+}
+
+impl<T: DataType> Mat_<T> {
+	/// Returns a read-only `ndarray` view into the `Mat_` data without copying it
+	///
+	/// Unlike `Mat::as_array_view()` the view elements are of type `T` and there is no separate channels axis.
+	pub fn as_array_view(&self) -> Result<ArrayViewD<T>> {
+		unsafe { array_view(self, false) }
+	}
+
+	/// Returns a writeable `ndarray` view into the `Mat_` data without copying it, see `as_array_view()`
+	pub fn as_array_view_mut(&mut self) -> Result<ArrayViewMutD<T>> {
+		unsafe { array_view_mut(self, false) }
+	}
+
+	/// Copies the `Mat_` data into an owned `ndarray` array with the same layout as `as_array_view()`
+	pub fn to_array(&self) -> Result<ArrayD<T>> {
+		self.as_array_view().map(|view| view.to_owned())
+	}
+
+	/// Creates a new `Mat_` with the shape of `arr` and copies its data
+	pub fn from_array<S: Data<Elem=T>, D: Dimension>(arr: &ArrayBase<S, D>) -> Result<Self> {
+		from_ndarray(arr, false)
+			.and_then(Self::try_from)
+	}
+}
+
+impl<'m, T: DataType> TryFrom<&'m Mat> for ArrayViewD<'m, T> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(mat: &'m Mat) -> Result<Self> {
+		mat.as_array_view()
+	}
+}
+
+impl<'m, T: DataType> TryFrom<&'m mut Mat> for ArrayViewMutD<'m, T> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(mat: &'m mut Mat) -> Result<Self> {
+		mat.as_array_view_mut()
+	}
+}
+
+impl<'m, T: DataType> TryFrom<&'m Mat_<T>> for ArrayViewD<'m, T> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(mat: &'m Mat_<T>) -> Result<Self> {
+		mat.as_array_view()
+	}
+}
+
+impl<'m, T: DataType> TryFrom<&'m mut Mat_<T>> for ArrayViewMutD<'m, T> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(mat: &'m mut Mat_<T>) -> Result<Self> {
+		mat.as_array_view_mut()
+	}
+}
+
+impl<T: DataType, S: Data<Elem=T>, D: Dimension> TryFrom<&ArrayBase<S, D>> for Mat {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(arr: &ArrayBase<S, D>) -> Result<Self> {
+		Self::from_array(arr)
+	}
+}
+
+impl<T: DataType, S: Data<Elem=T>, D: Dimension> TryFrom<&ArrayBase<S, D>> for Mat_<T> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(arr: &ArrayBase<S, D>) -> Result<Self> {
+		Self::from_array(arr)
+	}
+}
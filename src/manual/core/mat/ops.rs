@@ -0,0 +1,278 @@
+use std::{
+	borrow::Borrow,
+	fmt,
+	ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Sub},
+};
+
+use crate::{
+	core::{self, Mat, MatExpr, Scalar, ToInputArray},
+	prelude::*,
+	Result,
+};
+
+/// Result of the arithmetic operators applied to `Mat`, `MatExpr` and `Scalar`
+///
+/// Operators can't return `Result` directly and still be chained, so they return this wrapper which in turn also
+/// supports the same set of operators. This way expressions like `(&a - &b) * 0.5 + &c` are built lazily as
+/// `MatExpr` just like in C++. Call `into_result()` to get the final `MatExpr` or the first error that occurred
+/// during the evaluation, or `into_mat()` to also evaluate the expression into a `Mat`.
+#[must_use]
+pub struct MatExprResult<T>(Result<T>);
+
+impl<T> MatExprResult<T> {
+	#[inline]
+	pub fn into_result(self) -> Result<T> {
+		self.0
+	}
+}
+
+impl MatExprResult<MatExpr> {
+	/// Evaluates the expression into a new `Mat`
+	#[inline]
+	pub fn into_mat(self) -> Result<Mat> {
+		self.0.and_then(|expr| expr.to_mat())
+	}
+}
+
+impl<T> From<Result<T>> for MatExprResult<T> {
+	#[inline]
+	fn from(s: Result<T>) -> Self {
+		Self(s)
+	}
+}
+
+impl<T> From<MatExprResult<T>> for Result<T> {
+	#[inline]
+	fn from(s: MatExprResult<T>) -> Self {
+		s.0
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for MatExprResult<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_tuple("MatExprResult")
+			.field(&self.0)
+			.finish()
+	}
+}
+
+/// Element-wise comparison of arrays, the result is a `CV_8U` mask with 255 where the comparison holds and 0
+/// otherwise (see `core::compare()`)
+///
+/// Those are the counterparts of the C++ comparison operators on `Mat` and `MatExpr`, they can't be implemented
+/// via `PartialOrd` because the result is an array.
+pub trait MatCompare: ToInputArray + Sized {
+	/// Element-wise `self == rhs`
+	#[inline]
+	fn cmp_eq(&self, rhs: &dyn ToInputArray) -> MatExprResult<MatExpr> {
+		compare(self, rhs, core::CMP_EQ)
+	}
+
+	/// Element-wise `self != rhs`
+	#[inline]
+	fn cmp_ne(&self, rhs: &dyn ToInputArray) -> MatExprResult<MatExpr> {
+		compare(self, rhs, core::CMP_NE)
+	}
+
+	/// Element-wise `self < rhs`
+	#[inline]
+	fn cmp_lt(&self, rhs: &dyn ToInputArray) -> MatExprResult<MatExpr> {
+		compare(self, rhs, core::CMP_LT)
+	}
+
+	/// Element-wise `self <= rhs`
+	#[inline]
+	fn cmp_le(&self, rhs: &dyn ToInputArray) -> MatExprResult<MatExpr> {
+		compare(self, rhs, core::CMP_LE)
+	}
+
+	/// Element-wise `self > rhs`
+	#[inline]
+	fn cmp_gt(&self, rhs: &dyn ToInputArray) -> MatExprResult<MatExpr> {
+		compare(self, rhs, core::CMP_GT)
+	}
+
+	/// Element-wise `self >= rhs`
+	#[inline]
+	fn cmp_ge(&self, rhs: &dyn ToInputArray) -> MatExprResult<MatExpr> {
+		compare(self, rhs, core::CMP_GE)
+	}
+}
+
+impl MatCompare for Mat {}
+impl MatCompare for &Mat {}
+impl MatCompare for MatExpr {}
+impl MatCompare for &MatExpr {}
+impl MatCompare for Scalar {}
+
+fn compare(a: &dyn ToInputArray, b: &dyn ToInputArray, cmpop: i32) -> MatExprResult<MatExpr> {
+	let mut dst = Mat::default();
+	core::compare(a, b, &mut dst, cmpop)
+		.and_then(|_| MatExpr::from_mat(&dst))
+		.into()
+}
+
+type BitwiseFn = fn(&dyn ToInputArray, &dyn ToInputArray, &mut dyn core::ToOutputArray, &dyn ToInputArray) -> Result<()>;
+
+#[inline]
+fn bitwise(op: BitwiseFn, a: &dyn ToInputArray, b: &dyn ToInputArray) -> Result<MatExpr> {
+	let mut dst = Mat::default();
+	op(a, b, &mut dst, &core::no_array()?)
+		.and_then(|_| MatExpr::from_mat(&dst))
+}
+
+#[inline]
+fn bitwise_and(a: &dyn ToInputArray, b: &dyn ToInputArray) -> Result<MatExpr> {
+	bitwise(core::bitwise_and, a, b)
+}
+
+#[inline]
+fn bitwise_or(a: &dyn ToInputArray, b: &dyn ToInputArray) -> Result<MatExpr> {
+	bitwise(core::bitwise_or, a, b)
+}
+
+#[inline]
+fn bitwise_xor(a: &dyn ToInputArray, b: &dyn ToInputArray) -> Result<MatExpr> {
+	bitwise(core::bitwise_xor, a, b)
+}
+
+#[inline]
+fn bitwise_not(a: &dyn ToInputArray) -> Result<MatExpr> {
+	let mut dst = Mat::default();
+	core::bitwise_not(a, &mut dst, &core::no_array()?)
+		.and_then(|_| MatExpr::from_mat(&dst))
+}
+
+/// Unifies the operator arguments so that `MatExprResult` can be used interchangeably with `Mat` and `MatExpr`
+trait IntoOperand {
+	type Operand;
+
+	fn into_operand(self) -> Result<Self::Operand>;
+}
+
+macro_rules! into_operand {
+	($type: ty) => {
+		impl IntoOperand for $type {
+			type Operand = Self;
+
+			#[inline]
+			fn into_operand(self) -> Result<Self::Operand> {
+				Ok(self)
+			}
+		}
+	};
+}
+
+into_operand!(Mat);
+into_operand!(&Mat);
+into_operand!(MatExpr);
+into_operand!(&MatExpr);
+into_operand!(Scalar);
+into_operand!(f64);
+
+impl IntoOperand for MatExprResult<MatExpr> {
+	type Operand = MatExpr;
+
+	#[inline]
+	fn into_operand(self) -> Result<Self::Operand> {
+		self.0
+	}
+}
+
+macro_rules! operand_arg {
+	(mat, $a: ident) => { Borrow::<Mat>::borrow(&$a) };
+	(expr, $a: ident) => { Borrow::<MatExpr>::borrow(&$a) };
+	(val, $a: ident) => { $a };
+	(arr, $a: ident) => { &$a };
+}
+
+macro_rules! binary_op {
+	($trait: ident, $method: ident, $func: path, $lhs: ty => $lhs_arg: ident, $rhs: ty => $rhs_arg: ident) => {
+		impl $trait<$rhs> for $lhs {
+			type Output = MatExprResult<MatExpr>;
+
+			#[inline]
+			fn $method(self, rhs: $rhs) -> Self::Output {
+				self.into_operand()
+					.and_then(|a| rhs.into_operand().and_then(|b| $func(operand_arg!($lhs_arg, a), operand_arg!($rhs_arg, b))))
+					.into()
+			}
+		}
+	};
+}
+
+macro_rules! binary_ops {
+	($trait: ident, $method: ident, $func: path, [$($lhs: ty),+] => $lhs_arg: ident, $rhs: tt => $rhs_arg: ident) => {
+		$( binary_ops!(@rhs $trait, $method, $func, $lhs => $lhs_arg, $rhs => $rhs_arg); )+
+	};
+	(@rhs $trait: ident, $method: ident, $func: path, $lhs: ty => $lhs_arg: ident, [$($rhs: ty),+] => $rhs_arg: ident) => {
+		$( binary_op!($trait, $method, $func, $lhs => $lhs_arg, $rhs => $rhs_arg); )+
+	};
+}
+
+macro_rules! unary_op {
+	($trait: ident, $method: ident, $func: path, [$($type: ty),+] => $arg: ident) => {
+		$(
+			impl $trait for $type {
+				type Output = MatExprResult<MatExpr>;
+
+				#[inline]
+				fn $method(self) -> Self::Output {
+					self.into_operand()
+						.and_then(|a| $func(operand_arg!($arg, a)))
+						.into()
+				}
+			}
+		)+
+	};
+}
+
+binary_ops!(Add, add, core::add_mat_mat, [Mat, &Mat] => mat, [Mat, &Mat] => mat);
+binary_ops!(Add, add, core::add_mat_matexpr, [Mat, &Mat] => mat, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+binary_ops!(Add, add, core::add_mat_scalar, [Mat, &Mat] => mat, [Scalar] => val);
+binary_ops!(Add, add, core::add_matexpr_mat, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [Mat, &Mat] => mat);
+binary_ops!(Add, add, core::add_matexpr_matexpr, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+binary_ops!(Add, add, core::add_matexpr_scalar, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [Scalar] => val);
+binary_ops!(Add, add, core::add_scalar_mat, [Scalar] => val, [Mat, &Mat] => mat);
+binary_ops!(Add, add, core::add_scalar_matexpr, [Scalar] => val, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+
+binary_ops!(Sub, sub, core::sub_mat_mat, [Mat, &Mat] => mat, [Mat, &Mat] => mat);
+binary_ops!(Sub, sub, core::sub_mat_matexpr, [Mat, &Mat] => mat, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+binary_ops!(Sub, sub, core::sub_mat_scalar, [Mat, &Mat] => mat, [Scalar] => val);
+binary_ops!(Sub, sub, core::sub_matexpr_mat, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [Mat, &Mat] => mat);
+binary_ops!(Sub, sub, core::sub_matexpr_matexpr, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+binary_ops!(Sub, sub, core::sub_matexpr_scalar, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [Scalar] => val);
+binary_ops!(Sub, sub, core::sub_scalar_mat, [Scalar] => val, [Mat, &Mat] => mat);
+binary_ops!(Sub, sub, core::sub_scalar_matexpr, [Scalar] => val, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+
+// Mat * Mat is a matrix multiplication like in C++, use `MatTrait::mul()` for the per-element one
+binary_ops!(Mul, mul, core::mul_mat_mat, [Mat, &Mat] => mat, [Mat, &Mat] => mat);
+binary_ops!(Mul, mul, core::mul_mat_matexpr, [Mat, &Mat] => mat, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+binary_ops!(Mul, mul, core::mul_mat_f64, [Mat, &Mat] => mat, [f64] => val);
+binary_ops!(Mul, mul, core::mul_matexpr_mat, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [Mat, &Mat] => mat);
+binary_ops!(Mul, mul, core::mul_matexpr_matexpr, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+binary_ops!(Mul, mul, core::mul_matexpr_f64, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [f64] => val);
+binary_ops!(Mul, mul, core::mul_f64_mat, [f64] => val, [Mat, &Mat] => mat);
+binary_ops!(Mul, mul, core::mul_f64_matexpr, [f64] => val, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+
+binary_ops!(Div, div, core::div_mat_mat, [Mat, &Mat] => mat, [Mat, &Mat] => mat);
+binary_ops!(Div, div, core::div_mat_matexpr, [Mat, &Mat] => mat, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+binary_ops!(Div, div, core::div_mat_f64, [Mat, &Mat] => mat, [f64] => val);
+binary_ops!(Div, div, core::div_matexpr_mat, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [Mat, &Mat] => mat);
+binary_ops!(Div, div, core::div_matexpr_matexpr, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+binary_ops!(Div, div, core::div_matexpr_f64, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr, [f64] => val);
+binary_ops!(Div, div, core::div_f64_mat, [f64] => val, [Mat, &Mat] => mat);
+binary_ops!(Div, div, core::div_f64_matexpr, [f64] => val, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+
+unary_op!(Neg, neg, core::sub_mat, [Mat, &Mat] => mat);
+unary_op!(Neg, neg, core::sub_matexpr, [MatExpr, &MatExpr, MatExprResult<MatExpr>] => expr);
+
+// bitwise operators are evaluated eagerly because there are no corresponding MatExpr bindings
+binary_ops!(BitAnd, bitand, bitwise_and, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>] => arr, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>, Scalar] => arr);
+binary_ops!(BitAnd, bitand, bitwise_and, [Scalar] => arr, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>] => arr);
+binary_ops!(BitOr, bitor, bitwise_or, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>] => arr, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>, Scalar] => arr);
+binary_ops!(BitOr, bitor, bitwise_or, [Scalar] => arr, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>] => arr);
+binary_ops!(BitXor, bitxor, bitwise_xor, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>] => arr, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>, Scalar] => arr);
+binary_ops!(BitXor, bitxor, bitwise_xor, [Scalar] => arr, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>] => arr);
+
+unary_op!(Not, not, bitwise_not, [Mat, &Mat, MatExpr, &MatExpr, MatExprResult<MatExpr>] => arr);
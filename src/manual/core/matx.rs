@@ -1,20 +1,25 @@
 use std::{
+	convert::TryFrom,
 	ffi::c_void,
 	fmt,
 	mem::ManuallyDrop,
+	ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+	slice,
 };
 
 use num_traits::{One, Zero};
 
 use crate::{
-	core::{self, ToInputArray, ToInputOutputArray, ToOutputArray},
+	core::{self, DataType, Mat, ToInputArray, ToInputOutputArray, ToOutputArray},
 	Error,
-	manual::core::sized::*,
+	prelude::*,
 	Result,
-	sys::Result as SysResult,
+	sys,
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
 };
 
+use super::mat::{match_dims, match_format};
+
 fn index_check(idx: (usize, usize), rows: usize, cols: usize) -> Result<()> {
 	if idx.0 >= rows {
 		Err(Error::new(core::StsOutOfRange, format!("Index: {} along dimension: rows out of bounds 0..{}", idx.0, rows)))
@@ -88,53 +93,133 @@ pub trait MatxTrait: Sized {
 }
 
 /// [docs.opencv.org](https://docs.opencv.org/master/de/de1/classcv_1_1Matx.html)
+///
+/// Small matrix of any size known at compile time, the data is stored inline in row-major order so it can be
+/// passed to OpenCV directly. The elements are accessible either as rows through the `val` field or as a flat
+/// slice through `MatxTrait::val()`.
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct Matx<T: ValidMatxType, A: SizedArray<T>> {
-	pub val: A::Storage,
+pub struct Matx<T: ValidMatxType, const ROWS: usize, const COLS: usize> {
+	pub val: [[T; COLS]; ROWS],
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> Matx<T, A> {
-	pub fn from(s: A::Storage) -> Self {
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> Matx<T, ROWS, COLS> {
+	pub const ROWS: usize = ROWS;
+	pub const COLS: usize = COLS;
+
+	#[inline]
+	pub fn from(s: [[T; COLS]; ROWS]) -> Self {
 		Self { val: s }
 	}
+
+	/// Creates a new `Matx` from the slice containing the elements in row-major order
+	pub fn from_slice(s: &[T]) -> Result<Self> {
+		if s.len() != ROWS * COLS {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Slice length is: {}, but Matx requires: {} elements", s.len(), ROWS * COLS)));
+		}
+		let mut out = Self::zeros();
+		out.val_mut().copy_from_slice(s);
+		Ok(out)
+	}
+
+	/// Returns the transposed matrix
+	pub fn t(&self) -> Matx<T, COLS, ROWS> {
+		let mut out = Matx::<T, COLS, ROWS>::zeros();
+		for (row_n, row) in self.val.iter().enumerate() {
+			for (col_n, &x) in row.iter().enumerate() {
+				out.val[col_n][row_n] = x;
+			}
+		}
+		out
+	}
+
+	/// Returns the product of the matrices computed element-wise
+	pub fn mul_elem(&self, other: &Self) -> Self {
+		let mut out = *self;
+		out.val_mut().iter_mut()
+			.zip(other.val())
+			.for_each(|(x, &y)| *x *= y);
+		out
+	}
+
+	/// Dot product of the matrices treated as flat vectors
+	pub fn dot(&self, other: &Self) -> T {
+		self.val().iter()
+			.zip(other.val())
+			.fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+	}
+
+	/// Solves the linear system `self * x = rhs` using `core::solve()`, `method` is one of `core::DECOMP_*`
+	///
+	/// Returns an error if the matrix is singular and the chosen `method` can't handle that (e.g. `DECOMP_LU`).
+	pub fn solve<const RHS_COLS: usize>(&self, rhs: &Matx<T, ROWS, RHS_COLS>, method: i32) -> Result<Matx<T, COLS, RHS_COLS>> where T: DataType {
+		let mut out = Matx::<T, COLS, RHS_COLS>::zeros();
+		if core::solve(self, rhs, &mut out, method)? {
+			Ok(out)
+		} else {
+			Err(Error::new(core::StsBadArg, "Matrix is singular, can't solve the system".to_string()))
+		}
+	}
+
+	/// Copies the `Matx` data into a new `ROWS` x `COLS` single channel `Mat`
+	#[inline]
+	pub fn to_mat(&self) -> Result<Mat> where T: DataType {
+		Mat::from_slice_2d(&self.val[..])
+	}
+}
+
+impl<T: ValidMatxType, const N: usize> Matx<T, N, N> {
+	/// Returns the inverse of the square matrix using `core::invert()`, `method` is one of `core::DECOMP_*`
+	///
+	/// Returns an error if the matrix is singular.
+	pub fn inv(&self, method: i32) -> Result<Self> where T: DataType {
+		let mut out = Self::zeros();
+		if core::invert(self, &mut out, method)? != 0. {
+			Ok(out)
+		} else {
+			Err(Error::new(core::StsBadArg, "Matrix is singular, can't calculate the inverse".to_string()))
+		}
+	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> MatxTrait for Matx<T, A> {
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> MatxTrait for Matx<T, ROWS, COLS> {
 	type ElemType = T;
 
+	#[inline]
 	fn rows(&self) -> usize {
-		A::ROWS
+		ROWS
 	}
 
+	#[inline]
 	fn cols(&self) -> usize {
-		A::COLS
+		COLS
 	}
 
+	#[inline]
 	fn val(&self) -> &[Self::ElemType] {
-		self.val.as_ref()
+		// safe because nested arrays have no padding
+		unsafe { slice::from_raw_parts(self.val.as_ptr() as *const T, ROWS * COLS) }
 	}
 
+	#[inline]
 	fn val_mut(&mut self) -> &mut [Self::ElemType] {
-		self.val.as_mut()
+		// safe because nested arrays have no padding
+		unsafe { slice::from_raw_parts_mut(self.val.as_mut_ptr() as *mut T, ROWS * COLS) }
 	}
 
-	fn all(alpha: Self::ElemType) -> Self where Self: Sized {
-		Self { val: A::all(alpha) }
-	}
-
-	fn channels(&self) -> usize {
-		A::ROWS * A::COLS
+	#[inline]
+	fn all(alpha: Self::ElemType) -> Self {
+		Self { val: [[alpha; COLS]; ROWS] }
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> Default for Matx<T, A> {
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> Default for Matx<T, ROWS, COLS> {
 	fn default() -> Self {
 		Self::all(T::default())
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> std::ops::Index<(usize, usize)> for Matx<T, A> {
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for Matx<T, ROWS, COLS> {
 	type Output = T;
 
 	fn index(&self, index: (usize, usize)) -> &Self::Output {
@@ -142,13 +227,137 @@ impl<T: ValidMatxType, A: SizedArray<T>> std::ops::Index<(usize, usize)> for Mat
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> std::ops::IndexMut<(usize, usize)> for Matx<T, A> {
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> IndexMut<(usize, usize)> for Matx<T, ROWS, COLS> {
 	fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
 		self.get_mut(index).expect("Index out of range")
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> OpenCVType<'_> for Matx<T, A> {
+impl<T: ValidMatxType, const ROWS: usize, const COMMON: usize, const COLS: usize> Mul<Matx<T, COMMON, COLS>> for Matx<T, ROWS, COMMON> {
+	type Output = Matx<T, ROWS, COLS>;
+
+	/// Matrix multiplication
+	fn mul(self, rhs: Matx<T, COMMON, COLS>) -> Self::Output {
+		let mut out = Self::Output::zeros();
+		for (out_row, row) in out.val.iter_mut().zip(&self.val) {
+			for (col_n, out_x) in out_row.iter_mut().enumerate() {
+				*out_x = row.iter()
+					.zip(&rhs.val)
+					.fold(T::zero(), |acc, (&x, rhs_row)| acc + x * rhs_row[col_n]);
+			}
+		}
+		out
+	}
+}
+
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> AddAssign for Matx<T, ROWS, COLS> {
+	fn add_assign(&mut self, rhs: Self) {
+		self.val_mut().iter_mut()
+			.zip(rhs.val())
+			.for_each(|(x, &y)| *x += y);
+	}
+}
+
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> Add for Matx<T, ROWS, COLS> {
+	type Output = Self;
+
+	#[inline]
+	fn add(mut self, rhs: Self) -> Self::Output {
+		self += rhs;
+		self
+	}
+}
+
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> SubAssign for Matx<T, ROWS, COLS> {
+	fn sub_assign(&mut self, rhs: Self) {
+		self.val_mut().iter_mut()
+			.zip(rhs.val())
+			.for_each(|(x, &y)| *x -= y);
+	}
+}
+
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> Sub for Matx<T, ROWS, COLS> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(mut self, rhs: Self) -> Self::Output {
+		self -= rhs;
+		self
+	}
+}
+
+impl<T: ValidMatxType + Neg<Output=T>, const ROWS: usize, const COLS: usize> Neg for Matx<T, ROWS, COLS> {
+	type Output = Self;
+
+	fn neg(mut self) -> Self::Output {
+		self.val_mut().iter_mut()
+			.for_each(|x| *x = -*x);
+		self
+	}
+}
+
+macro_rules! matx_scale {
+	($type: ty) => {
+		impl<const ROWS: usize, const COLS: usize> MulAssign<$type> for Matx<$type, ROWS, COLS> {
+			fn mul_assign(&mut self, rhs: $type) {
+				self.val_mut().iter_mut()
+					.for_each(|x| *x *= rhs);
+			}
+		}
+
+		impl<const ROWS: usize, const COLS: usize> Mul<$type> for Matx<$type, ROWS, COLS> {
+			type Output = Self;
+
+			#[inline]
+			fn mul(mut self, rhs: $type) -> Self::Output {
+				self *= rhs;
+				self
+			}
+		}
+
+		impl<const ROWS: usize, const COLS: usize> Mul<Matx<$type, ROWS, COLS>> for $type {
+			type Output = Matx<$type, ROWS, COLS>;
+
+			#[inline]
+			fn mul(self, rhs: Matx<$type, ROWS, COLS>) -> Self::Output {
+				rhs * self
+			}
+		}
+	};
+}
+
+matx_scale!(f32);
+matx_scale!(f64);
+
+impl<T: ValidMatxType + DataType, const ROWS: usize, const COLS: usize> TryFrom<&Mat> for Matx<T, ROWS, COLS> {
+	type Error = Error;
+
+	/// Copies the data of a 2D single channel `ROWS` x `COLS` `Mat` of the matching type
+	fn try_from(mat: &Mat) -> Result<Self> {
+		match_format::<T>(mat.typ()?)
+			.and_then(|_| match_dims(mat, 2))?;
+		let size = mat.size()?;
+		if size.height as usize != ROWS || size.width as usize != COLS {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Mat size is: {}x{}, but requested Matx size is: {}x{}", size.height, size.width, ROWS, COLS)));
+		}
+		let mut out = Self::zeros();
+		for (row_n, row) in out.val.iter_mut().enumerate() {
+			row.copy_from_slice(mat.at_row::<T>(row_n as i32)?);
+		}
+		Ok(out)
+	}
+}
+
+impl<T: ValidMatxType + DataType, const ROWS: usize, const COLS: usize> TryFrom<Mat> for Matx<T, ROWS, COLS> {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(mat: Mat) -> Result<Self> {
+		Self::try_from(&mat)
+	}
+}
+
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> OpenCVType<'_> for Matx<T, ROWS, COLS> {
 	type Arg = Self;
 	type ExternReceive = Self;
 	type ExternContainer = Self;
@@ -158,7 +367,7 @@ impl<T: ValidMatxType, A: SizedArray<T>> OpenCVType<'_> for Matx<T, A> {
 	#[inline] unsafe fn opencv_from_extern(s: Self) -> Self { s }
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> OpenCVTypeArg<'_> for Matx<T, A> {
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> OpenCVTypeArg<'_> for Matx<T, ROWS, COLS> {
 	type ExternContainer = Self;
 
 	#[inline]
@@ -167,7 +376,7 @@ impl<T: ValidMatxType, A: SizedArray<T>> OpenCVTypeArg<'_> for Matx<T, A> {
 	fn opencv_into_extern_container_nofail(self) -> Self::ExternContainer { self }
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> OpenCVTypeExternContainer for Matx<T, A> {
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> OpenCVTypeExternContainer for Matx<T, ROWS, COLS> {
 	type ExternSend = *const Self;
 	type ExternSendMut = *mut Self;
 
@@ -176,143 +385,91 @@ impl<T: ValidMatxType, A: SizedArray<T>> OpenCVTypeExternContainer for Matx<T, A
 	#[inline] fn opencv_into_extern(self) -> Self::ExternSendMut { &mut *ManuallyDrop::new(self) as _ }
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> std::cmp::PartialEq for Matx<T, A> {
-	fn eq(&self, other: &Matx<T, A>) -> bool {
+impl<T: ValidMatxType, const ROWS: usize, const COLS: usize> PartialEq for Matx<T, ROWS, COLS> {
+	fn eq(&self, other: &Self) -> bool {
 		self.val() == other.val()
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> fmt::Debug for Matx<T, A> {
+impl<T: ValidMatxType + fmt::Debug, const ROWS: usize, const COLS: usize> fmt::Debug for Matx<T, ROWS, COLS> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		f.debug_struct("Matx")
 			.field("rows", &self.rows())
 			.field("cols", &self.cols())
-			.field("channels", &self.channels())
-			.field("shortdim", &self.shortdim())
+			.field("val", &self.val)
 			.finish()
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToInputArray for Matx<T, A> where Self: MatxExtern<T, A> {
+extern "C" {
+	fn cv_manual_Matx_input_array(instance: *const c_void, typ: i32, rows: i32, cols: i32) -> sys::Result<*mut c_void>;
+	fn cv_manual_Matx_output_array(instance: *mut c_void, typ: i32, rows: i32, cols: i32) -> sys::Result<*mut c_void>;
+	fn cv_manual_Matx_input_output_array(instance: *mut c_void, typ: i32, rows: i32, cols: i32) -> sys::Result<*mut c_void>;
+}
+
+impl<T: ValidMatxType + DataType, const ROWS: usize, const COLS: usize> ToInputArray for Matx<T, ROWS, COLS> {
 	fn input_array(&self) -> Result<core::_InputArray> {
-		unsafe { self.extern_input_array() }
+		unsafe { cv_manual_Matx_input_array(self as *const _ as _, T::typ(), ROWS as i32, COLS as i32) }
 			.into_result()
 			.map(|ptr| unsafe { core::_InputArray::from_raw(ptr) })
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToInputArray for &Matx<T, A> where Matx<T, A>: MatxExtern<T, A> {
+impl<T: ValidMatxType + DataType, const ROWS: usize, const COLS: usize> ToInputArray for &Matx<T, ROWS, COLS> {
+	#[inline]
 	fn input_array(&self) -> Result<core::_InputArray> {
 		(*self).input_array()
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToOutputArray for Matx<T, A> where Self: MatxExtern<T, A> {
+impl<T: ValidMatxType + DataType, const ROWS: usize, const COLS: usize> ToOutputArray for Matx<T, ROWS, COLS> {
 	fn output_array(&mut self) -> Result<core::_OutputArray> {
-		unsafe { self.extern_output_array() }
+		unsafe { cv_manual_Matx_output_array(self as *mut _ as _, T::typ(), ROWS as i32, COLS as i32) }
 			.into_result()
 			.map(|ptr| unsafe { core::_OutputArray::from_raw(ptr) })
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToOutputArray for &mut Matx<T, A> where Matx<T, A>: MatxExtern<T, A> {
+impl<T: ValidMatxType + DataType, const ROWS: usize, const COLS: usize> ToOutputArray for &mut Matx<T, ROWS, COLS> {
+	#[inline]
 	fn output_array(&mut self) -> Result<core::_OutputArray> {
 		(*self).output_array()
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToInputOutputArray for Matx<T, A> where Self: MatxExtern<T, A> {
+impl<T: ValidMatxType + DataType, const ROWS: usize, const COLS: usize> ToInputOutputArray for Matx<T, ROWS, COLS> {
 	fn input_output_array(&mut self) -> Result<core::_InputOutputArray> {
-		unsafe { self.extern_input_output_array() }
+		unsafe { cv_manual_Matx_input_output_array(self as *mut _ as _, T::typ(), ROWS as i32, COLS as i32) }
 			.into_result()
 			.map(|ptr| unsafe { core::_InputOutputArray::from_raw(ptr) })
 	}
 }
 
-impl<T: ValidMatxType, A: SizedArray<T>> ToInputOutputArray for &mut Matx<T, A> where Matx<T, A>: MatxExtern<T, A> {
+impl<T: ValidMatxType + DataType, const ROWS: usize, const COLS: usize> ToInputOutputArray for &mut Matx<T, ROWS, COLS> {
+	#[inline]
 	fn input_output_array(&mut self) -> Result<core::_InputOutputArray> {
 		(*self).input_output_array()
 	}
 }
 
-#[doc(hidden)]
-pub trait MatxExtern<T: ValidMatxType, A: SizedArray<T>> {
-	#[doc(hidden)] unsafe fn extern_input_array(&self) -> SysResult<*mut c_void>;
-	#[doc(hidden)] unsafe fn extern_output_array(&mut self) -> SysResult<*mut c_void>;
-	#[doc(hidden)] unsafe fn extern_input_output_array(&mut self) -> SysResult<*mut c_void>;
-}
+pub type Matx12<T> = Matx<T, 1, 2>;
+pub type Matx13<T> = Matx<T, 1, 3>;
+pub type Matx14<T> = Matx<T, 1, 4>;
+pub type Matx16<T> = Matx<T, 1, 6>;
 
-macro_rules! matx_extern {
-	($type: ty, $array: ty, $extern_input_array: ident, $extern_ouput_array: ident, $extern_input_array_output: ident) => {
-		impl $crate::manual::core::MatxExtern<$type, $array> for $crate::manual::core::Matx<$type, $array> {
-			unsafe fn extern_input_array(&self) -> $crate::sys::Result<*mut c_void> {
-				extern "C" { fn $extern_input_array(instance: *const $crate::manual::core::Matx<$type, $array>) -> $crate::sys::Result<*mut c_void>; }
-				$extern_input_array(self)
-			}
+pub type Matx21<T> = Matx<T, 2, 1>;
+pub type Matx31<T> = Matx<T, 3, 1>;
+pub type Matx41<T> = Matx<T, 4, 1>;
+pub type Matx61<T> = Matx<T, 6, 1>;
 
-			unsafe fn extern_output_array(&mut self) -> $crate::sys::Result<*mut c_void> {
-				extern "C" { fn $extern_ouput_array(instance: *mut $crate::manual::core::Matx<$type, $array>) -> $crate::sys::Result<*mut c_void>; }
-				$extern_ouput_array(self)
-			}
+pub type Matx22<T> = Matx<T, 2, 2>;
+pub type Matx23<T> = Matx<T, 2, 3>;
+pub type Matx32<T> = Matx<T, 3, 2>;
 
-			unsafe fn extern_input_output_array(&mut self) -> $crate::sys::Result<*mut c_void> {
-				extern "C" { fn $extern_input_array_output(instance: *mut $crate::manual::core::Matx<$type, $array>) -> $crate::sys::Result<*mut c_void>; }
-				$extern_input_array_output(self)
-			}
-		}
-	}
-}
+pub type Matx33<T> = Matx<T, 3, 3>;
+
+pub type Matx34<T> = Matx<T, 3, 4>;
+pub type Matx43<T> = Matx<T, 4, 3>;
 
-pub type Matx12<T> = Matx<T, SizedArray12>;
-matx_extern!(f32, SizedArray12, cv_Matx12f_input_array, cv_Matx12f_output_array, cv_Matx12f_input_output_array);
-matx_extern!(f64, SizedArray12, cv_Matx12d_input_array, cv_Matx12d_output_array, cv_Matx12d_input_output_array);
-pub type Matx13<T> = Matx<T, SizedArray13>;
-matx_extern!(f32, SizedArray13, cv_Matx13f_input_array, cv_Matx13f_output_array, cv_Matx13f_input_output_array);
-matx_extern!(f64, SizedArray13, cv_Matx13d_input_array, cv_Matx13d_output_array, cv_Matx13d_input_output_array);
-pub type Matx14<T> = Matx<T, SizedArray14>;
-matx_extern!(f32, SizedArray14, cv_Matx14f_input_array, cv_Matx14f_output_array, cv_Matx14f_input_output_array);
-matx_extern!(f64, SizedArray14, cv_Matx14d_input_array, cv_Matx14d_output_array, cv_Matx14d_input_output_array);
-pub type Matx16<T> = Matx<T, SizedArray16>;
-matx_extern!(f32, SizedArray16, cv_Matx16f_input_array, cv_Matx16f_output_array, cv_Matx16f_input_output_array);
-matx_extern!(f64, SizedArray16, cv_Matx16d_input_array, cv_Matx16d_output_array, cv_Matx16d_input_output_array);
-
-pub type Matx21<T> = Matx<T, SizedArray21>;
-matx_extern!(f32, SizedArray21, cv_Matx21f_input_array, cv_Matx21f_output_array, cv_Matx21f_input_output_array);
-matx_extern!(f64, SizedArray21, cv_Matx21d_input_array, cv_Matx21d_output_array, cv_Matx21d_input_output_array);
-pub type Matx31<T> = Matx<T, SizedArray31>;
-matx_extern!(f32, SizedArray31, cv_Matx31f_input_array, cv_Matx31f_output_array, cv_Matx31f_input_output_array);
-matx_extern!(f64, SizedArray31, cv_Matx31d_input_array, cv_Matx31d_output_array, cv_Matx31d_input_output_array);
-pub type Matx41<T> = Matx<T, SizedArray41>;
-matx_extern!(f32, SizedArray41, cv_Matx41f_input_array, cv_Matx41f_output_array, cv_Matx41f_input_output_array);
-matx_extern!(f64, SizedArray41, cv_Matx41d_input_array, cv_Matx41d_output_array, cv_Matx41d_input_output_array);
-pub type Matx61<T> = Matx<T, SizedArray61>;
-matx_extern!(f32, SizedArray61, cv_Matx61f_input_array, cv_Matx61f_output_array, cv_Matx61f_input_output_array);
-matx_extern!(f64, SizedArray61, cv_Matx61d_input_array, cv_Matx61d_output_array, cv_Matx61d_input_output_array);
-
-pub type Matx22<T> = Matx<T, SizedArray22>;
-matx_extern!(f32, SizedArray22, cv_Matx22f_input_array, cv_Matx22f_output_array, cv_Matx22f_input_output_array);
-matx_extern!(f64, SizedArray22, cv_Matx22d_input_array, cv_Matx22d_output_array, cv_Matx22d_input_output_array);
-pub type Matx23<T> = Matx<T, SizedArray23>;
-matx_extern!(f32, SizedArray23, cv_Matx23f_input_array, cv_Matx23f_output_array, cv_Matx23f_input_output_array);
-matx_extern!(f64, SizedArray23, cv_Matx23d_input_array, cv_Matx23d_output_array, cv_Matx23d_input_output_array);
-pub type Matx32<T> = Matx<T, SizedArray32>;
-matx_extern!(f32, SizedArray32, cv_Matx32f_input_array, cv_Matx32f_output_array, cv_Matx32f_input_output_array);
-matx_extern!(f64, SizedArray32, cv_Matx32d_input_array, cv_Matx32d_output_array, cv_Matx32d_input_output_array);
-
-pub type Matx33<T> = Matx<T, SizedArray33>;
-matx_extern!(f32, SizedArray33, cv_Matx33f_input_array, cv_Matx33f_output_array, cv_Matx33f_input_output_array);
-matx_extern!(f64, SizedArray33, cv_Matx33d_input_array, cv_Matx33d_output_array, cv_Matx33d_input_output_array);
-
-pub type Matx34<T> = Matx<T, SizedArray34>;
-matx_extern!(f32, SizedArray34, cv_Matx34f_input_array, cv_Matx34f_output_array, cv_Matx34f_input_output_array);
-matx_extern!(f64, SizedArray34, cv_Matx34d_input_array, cv_Matx34d_output_array, cv_Matx34d_input_output_array);
-pub type Matx43<T> = Matx<T, SizedArray43>;
-matx_extern!(f32, SizedArray43, cv_Matx43f_input_array, cv_Matx43f_output_array, cv_Matx43f_input_output_array);
-matx_extern!(f64, SizedArray43, cv_Matx43d_input_array, cv_Matx43d_output_array, cv_Matx43d_input_output_array);
-
-pub type Matx44<T> = Matx<T, SizedArray44>;
-matx_extern!(f32, SizedArray44, cv_Matx44f_input_array, cv_Matx44f_output_array, cv_Matx44f_input_output_array);
-matx_extern!(f64, SizedArray44, cv_Matx44d_input_array, cv_Matx44d_output_array, cv_Matx44d_input_output_array);
-pub type Matx66<T> = Matx<T, SizedArray66>;
-matx_extern!(f32, SizedArray66, cv_Matx66f_input_array, cv_Matx66f_output_array, cv_Matx66f_input_output_array);
-matx_extern!(f64, SizedArray66, cv_Matx66d_input_array, cv_Matx66d_output_array, cv_Matx66d_input_output_array);
+pub type Matx44<T> = Matx<T, 4, 4>;
+pub type Matx66<T> = Matx<T, 6, 6>;
@@ -11,6 +11,7 @@ valid_types!(ValidPointType: i32, i64, f32, f64);
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// [docs.opencv.org](https://docs.opencv.org/master/db/d4e/classcv_1_1Point__.html)
 pub struct Point_<T: ValidPointType> {
 	pub x: T,
@@ -0,0 +1,231 @@
+use std::{
+	convert::TryFrom,
+	fmt,
+	slice,
+};
+
+use serde::{
+	de::{self, SeqAccess, Visitor},
+	Deserialize,
+	Deserializer,
+	ser,
+	Serialize,
+	Serializer,
+};
+
+use crate::{
+	core::{DataType, DMatch, KeyPoint, Mat, Mat_, Matx, Point2f, RotatedRect, Scalar, Size2f, TermCriteria, ValidMatxType},
+	prelude::*,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "KeyPoint", rename = "KeyPoint")]
+struct KeyPointDef {
+	pt: Point2f,
+	size: f32,
+	angle: f32,
+	response: f32,
+	octave: i32,
+	class_id: i32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "DMatch", rename = "DMatch")]
+struct DMatchDef {
+	query_idx: i32,
+	train_idx: i32,
+	img_idx: i32,
+	distance: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "TermCriteria", rename = "TermCriteria")]
+struct TermCriteriaDef {
+	typ: i32,
+	max_count: i32,
+	epsilon: f64,
+}
+
+macro_rules! serde_remote {
+	($type: ty, $def: ty) => {
+		impl Serialize for $type {
+			#[inline]
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				<$def>::serialize(self, serializer)
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $type {
+			#[inline]
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				<$def>::deserialize(deserializer)
+			}
+		}
+	};
+}
+
+serde_remote!(KeyPoint, KeyPointDef);
+serde_remote!(DMatch, DMatchDef);
+serde_remote!(TermCriteria, TermCriteriaDef);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "RotatedRect")]
+struct RotatedRectRepr {
+	center: Point2f,
+	size: Size2f,
+	angle: f32,
+}
+
+impl Serialize for RotatedRect {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		RotatedRectRepr { center: self.center(), size: self.size(), angle: self.angle() }
+			.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for RotatedRect {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let repr = RotatedRectRepr::deserialize(deserializer)?;
+		RotatedRect::new(repr.center, repr.size, repr.angle)
+			.map_err(de::Error::custom)
+	}
+}
+
+/// `Matx` is serialized as a flat sequence of its elements in row-major order
+impl<T: ValidMatxType + Serialize, const ROWS: usize, const COLS: usize> Serialize for Matx<T, ROWS, COLS> {
+	#[inline]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.val().serialize(serializer)
+	}
+}
+
+impl<'de, T: ValidMatxType + Deserialize<'de>, const ROWS: usize, const COLS: usize> Deserialize<'de> for Matx<T, ROWS, COLS> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let val = Vec::<T>::deserialize(deserializer)?;
+		Self::from_slice(&val)
+			.map_err(de::Error::custom)
+	}
+}
+
+/// Raw data of the `Mat`, serialized as bytes so that binary formats can store it efficiently
+struct Bytes<'b>(&'b [u8]);
+
+impl Serialize for Bytes<'_> {
+	#[inline]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_bytes(self.0)
+	}
+}
+
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct ByteBufVisitor;
+
+		impl<'de> Visitor<'de> for ByteBufVisitor {
+			type Value = ByteBuf;
+
+			fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				f.write_str("byte array")
+			}
+
+			fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+				Ok(ByteBuf(v.to_vec()))
+			}
+
+			fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+				Ok(ByteBuf(v))
+			}
+
+			fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+				while let Some(x) = seq.next_element()? {
+					out.push(x);
+				}
+				Ok(ByteBuf(out))
+			}
+		}
+
+		deserializer.deserialize_byte_buf(ByteBufVisitor)
+	}
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Mat")]
+struct MatRepr<'b> {
+	typ: i32,
+	dims: Vec<i32>,
+	data: Bytes<'b>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Mat")]
+struct MatReprOwned {
+	typ: i32,
+	dims: Vec<i32>,
+	data: ByteBuf,
+}
+
+/// `Mat` is serialized as a struct with its type, size along each dimension and the continuous element data as
+/// raw bytes
+///
+/// Non-continuous matrices (e.g. ROIs) are copied before serialization.
+impl Serialize for Mat {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let dims = self.mat_size().to_vec();
+		let typ = self.typ().map_err(ser::Error::custom)?;
+		let continuous;
+		let mat = if !self.is_allocated() || self.is_continuous().map_err(ser::Error::custom)? {
+			self
+		} else {
+			continuous = self.try_clone().map_err(ser::Error::custom)?;
+			&continuous
+		};
+		let data = if mat.is_allocated() {
+			let len = mat.total().and_then(|total| mat.elem_size().map(|elem_size| total * elem_size))
+				.map_err(ser::Error::custom)?;
+			let data = mat.data().map_err(ser::Error::custom)?;
+			unsafe { slice::from_raw_parts(data as *const u8, len) }
+		} else {
+			&[]
+		};
+		MatRepr { typ, dims, data: Bytes(data) }
+			.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Mat {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let repr = MatReprOwned::deserialize(deserializer)?;
+		if repr.dims.is_empty() {
+			return Ok(Mat::default());
+		}
+		let mut out = Mat::new_nd_with_default(&repr.dims, repr.typ, Scalar::all(0.))
+			.map_err(de::Error::custom)?;
+		let len = out.total().and_then(|total| out.elem_size().map(|elem_size| total * elem_size))
+			.map_err(de::Error::custom)?;
+		if repr.data.0.len() != len {
+			return Err(de::Error::invalid_length(repr.data.0.len(), &format!("{} bytes of Mat data", len).as_str()));
+		}
+		if len > 0 {
+			unsafe { slice::from_raw_parts_mut(out.data_mut() as *mut u8, len) }
+				.copy_from_slice(&repr.data.0);
+		}
+		Ok(out)
+	}
+}
+
+impl<T: DataType> Serialize for Mat_<T> {
+	#[inline]
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.as_untyped().serialize(serializer)
+	}
+}
+
+impl<'de, T: DataType> Deserialize<'de> for Mat_<T> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Mat::deserialize(deserializer)
+			.and_then(|mat| Self::try_from(mat).map_err(de::Error::custom))
+	}
+}
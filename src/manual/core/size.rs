@@ -11,6 +11,7 @@ valid_types!(ValidSizeType: i32, i64, f32, f64);
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// [docs.opencv.org](https://docs.opencv.org/master/d6/d50/classcv_1_1Size__.html)
 pub struct Size_<T: ValidSizeType> {
 	pub width: T,
@@ -24,6 +24,7 @@ macro_rules! vec_impl {
 		/// [docs.opencv.org](https://docs.opencv.org/master/d6/dcf/classcv_1_1Vec.html)
 		#[repr(C)]
 		#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+		#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 		pub struct $type<T: $type_trait>(pub [T; $count]);
 
 		impl<T: $type_trait> $type<T> {
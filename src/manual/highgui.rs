@@ -0,0 +1,11 @@
+pub use event::*;
+#[cfg(feature = "highgui-recording")]
+pub use recording::*;
+pub use window::*;
+
+mod backend;
+mod event;
+pub(crate) mod intercept;
+#[cfg(feature = "highgui-recording")]
+mod recording;
+mod window;
@@ -0,0 +1,125 @@
+use std::{
+	ffi::{c_void, CString},
+	os::raw::c_char,
+};
+
+use crate::{
+	core::ToInputArray,
+	highgui,
+	prelude::*,
+	Result,
+	sys,
+};
+#[cfg(feature = "highgui-recording")]
+use super::recording::{self, RecorderHandle};
+
+pub(crate) type MouseTrampoline = unsafe extern "C" fn(i32, i32, i32, i32, *mut c_void);
+pub(crate) type TrackbarTrampoline = unsafe extern "C" fn(i32, *mut c_void);
+
+/// Implementation of the GUI calls used by `Window`, selected when the window is created
+///
+/// `OpenCv` calls the bindings directly, bypassing the `Recorder` interception of the free `highgui` functions, so that
+/// the windows created before the recorder was installed keep using the OpenCV GUI.
+pub(crate) enum Backend {
+	OpenCv,
+	#[cfg(feature = "highgui-recording")]
+	Recording(RecorderHandle),
+}
+
+impl Backend {
+	/// `Recording` if there is a `Recorder` installed on the current thread, `OpenCv` otherwise
+	pub fn current() -> Self {
+		#[cfg(feature = "highgui-recording")]
+		if let Some(handle) = recording::active() {
+			return Self::Recording(handle);
+		}
+		Self::OpenCv
+	}
+
+	pub fn named_window(&self, name: &str, flags: i32) -> Result<()> {
+		match self {
+			Self::OpenCv => {
+				let name = CString::new(name)?;
+				unsafe { sys::cv_namedWindow_const_StringR_int(name.as_ptr(), flags) }.into_result()
+			}
+			#[cfg(feature = "highgui-recording")]
+			Self::Recording(handle) => handle.named_window(name, flags),
+		}
+	}
+
+	pub fn set_mouse_callback(&self, name: &str, on_mouse: MouseTrampoline, userdata: *mut c_void) -> Result<()> {
+		match self {
+			Self::OpenCv => {
+				let name = CString::new(name)?;
+				unsafe { cv_manual_setMouseCallback(name.as_ptr(), Some(on_mouse), userdata) }.into_result()
+			}
+			#[cfg(feature = "highgui-recording")]
+			Self::Recording(handle) => handle.set_mouse_callback(name, on_mouse, userdata),
+		}
+	}
+
+	pub fn create_trackbar(&self, name: &str, winname: &str, count: i32, on_change: TrackbarTrampoline, userdata: *mut c_void) -> Result<()> {
+		match self {
+			Self::OpenCv => {
+				let name = CString::new(name)?;
+				let winname = CString::new(winname)?;
+				unsafe { cv_manual_createTrackbar(name.as_ptr(), winname.as_ptr(), count, Some(on_change), userdata) }.into_result()
+					.map(|_| ())
+			}
+			#[cfg(feature = "highgui-recording")]
+			Self::Recording(handle) => handle.create_trackbar(name, winname, count, on_change, userdata),
+		}
+	}
+
+	pub fn imshow(&self, winname: &str, mat: &dyn ToInputArray) -> Result<()> {
+		match self {
+			Self::OpenCv => {
+				let winname = CString::new(winname)?;
+				let mat = mat.input_array()?;
+				unsafe { sys::cv_imshow_const_StringR_const__InputArrayR(winname.as_ptr(), mat.as_raw__InputArray()) }.into_result()
+			}
+			#[cfg(feature = "highgui-recording")]
+			Self::Recording(handle) => handle.imshow(winname, mat),
+		}
+	}
+
+	pub fn get_trackbar_pos(&self, name: &str, winname: &str) -> Result<i32> {
+		match self {
+			Self::OpenCv => highgui::get_trackbar_pos(name, winname),
+			#[cfg(feature = "highgui-recording")]
+			Self::Recording(handle) => handle.get_trackbar_pos(name, winname),
+		}
+	}
+
+	pub fn set_trackbar_pos(&self, name: &str, winname: &str, pos: i32) -> Result<()> {
+		match self {
+			Self::OpenCv => highgui::set_trackbar_pos(name, winname, pos),
+			#[cfg(feature = "highgui-recording")]
+			Self::Recording(handle) => handle.set_trackbar_pos(name, winname, pos),
+		}
+	}
+
+	pub fn wait_key_ex(&self, delay: i32) -> Result<i32> {
+		match self {
+			Self::OpenCv => unsafe { sys::cv_waitKeyEx_int(delay) }.into_result(),
+			#[cfg(feature = "highgui-recording")]
+			Self::Recording(handle) => handle.wait_key_ex(),
+		}
+	}
+
+	pub fn destroy_window(&self, name: &str) -> Result<()> {
+		match self {
+			Self::OpenCv => {
+				let name = CString::new(name)?;
+				unsafe { sys::cv_destroyWindow_const_StringR(name.as_ptr()) }.into_result()
+			}
+			#[cfg(feature = "highgui-recording")]
+			Self::Recording(handle) => handle.destroy_window(name),
+		}
+	}
+}
+
+extern "C" {
+	fn cv_manual_setMouseCallback(winname: *const c_char, on_mouse: Option<MouseTrampoline>, userdata: *mut c_void) -> sys::Result_void;
+	fn cv_manual_createTrackbar(trackbarname: *const c_char, winname: *const c_char, count: i32, on_change: Option<TrackbarTrampoline>, userdata: *mut c_void) -> sys::Result<i32>;
+}
@@ -0,0 +1,107 @@
+use std::convert::TryFrom;
+
+use crate::{
+	core::Point,
+	highgui::{self, MouseEventTypes},
+};
+
+/// Code of the pressed key as returned by `wait_key_ex()`
+///
+/// Codes of the non-character keys like arrows depend on the platform and the GUI backend.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyCode(i32);
+
+impl KeyCode {
+	pub const BACKSPACE: Self = Self(8);
+	pub const TAB: Self = Self(9);
+	pub const ESCAPE: Self = Self(27);
+	pub const SPACE: Self = Self(32);
+
+	#[inline]
+	pub const fn from_code(code: i32) -> Self {
+		Self(code)
+	}
+
+	#[inline]
+	pub const fn code(self) -> i32 {
+		self.0
+	}
+
+	/// Character of the key if it's in the ASCII range
+	#[inline]
+	pub fn to_char(self) -> Option<char> {
+		u8::try_from(self.0).ok()
+			.filter(u8::is_ascii)
+			.map(char::from)
+	}
+}
+
+impl From<char> for KeyCode {
+	#[inline]
+	fn from(c: char) -> Self {
+		Self(c as i32)
+	}
+}
+
+/// State of the mouse buttons and modifier keys during the mouse event, wrapper for `EVENT_FLAG_*` constants
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MouseFlags(i32);
+
+impl MouseFlags {
+	pub const LBUTTON: Self = Self(highgui::EVENT_FLAG_LBUTTON);
+	pub const RBUTTON: Self = Self(highgui::EVENT_FLAG_RBUTTON);
+	pub const MBUTTON: Self = Self(highgui::EVENT_FLAG_MBUTTON);
+	pub const CTRLKEY: Self = Self(highgui::EVENT_FLAG_CTRLKEY);
+	pub const SHIFTKEY: Self = Self(highgui::EVENT_FLAG_SHIFTKEY);
+	pub const ALTKEY: Self = Self(highgui::EVENT_FLAG_ALTKEY);
+
+	#[inline]
+	pub const fn from_bits(bits: i32) -> Self {
+		Self(bits)
+	}
+
+	#[inline]
+	pub const fn bits(self) -> i32 {
+		self.0
+	}
+
+	/// Checks whether all bits of `other` are set in `self`
+	#[inline]
+	pub const fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+
+	/// Wheel rotation for `EVENT_MOUSEWHEEL` and `EVENT_MOUSEHWHEEL`, same as `get_mouse_wheel_delta()`
+	#[inline]
+	pub const fn wheel_delta(self) -> i32 {
+		self.0 >> 16
+	}
+}
+
+/// Event received by a `Window`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+	/// Key was pressed while any of the windows was focused
+	Key(KeyCode),
+	Mouse { kind: MouseEventTypes, pos: Point, flags: MouseFlags },
+	/// Position of the trackbar with the specified name has changed
+	Trackbar { name: String, pos: i32 },
+}
+
+pub(crate) fn mouse_event_type(event: i32) -> Option<MouseEventTypes> {
+	Some(match event {
+		highgui::EVENT_MOUSEMOVE => MouseEventTypes::EVENT_MOUSEMOVE,
+		highgui::EVENT_LBUTTONDOWN => MouseEventTypes::EVENT_LBUTTONDOWN,
+		highgui::EVENT_RBUTTONDOWN => MouseEventTypes::EVENT_RBUTTONDOWN,
+		highgui::EVENT_MBUTTONDOWN => MouseEventTypes::EVENT_MBUTTONDOWN,
+		highgui::EVENT_LBUTTONUP => MouseEventTypes::EVENT_LBUTTONUP,
+		highgui::EVENT_RBUTTONUP => MouseEventTypes::EVENT_RBUTTONUP,
+		highgui::EVENT_MBUTTONUP => MouseEventTypes::EVENT_MBUTTONUP,
+		highgui::EVENT_LBUTTONDBLCLK => MouseEventTypes::EVENT_LBUTTONDBLCLK,
+		highgui::EVENT_RBUTTONDBLCLK => MouseEventTypes::EVENT_RBUTTONDBLCLK,
+		highgui::EVENT_MBUTTONDBLCLK => MouseEventTypes::EVENT_MBUTTONDBLCLK,
+		highgui::EVENT_MOUSEWHEEL => MouseEventTypes::EVENT_MOUSEWHEEL,
+		highgui::EVENT_MOUSEHWHEEL => MouseEventTypes::EVENT_MOUSEHWHEEL,
+		_ => return None,
+	})
+}
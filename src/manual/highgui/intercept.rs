@@ -0,0 +1,82 @@
+// Hooks called by the generated `highgui` functions before calling into OpenCV, see `FUNC_INTERCEPT` in the binding
+// generator settings. They return `Some` when the call was handled by the `Recorder` installed on the current thread.
+
+use crate::{
+	core::ToInputArray,
+	Result,
+};
+#[cfg(feature = "highgui-recording")]
+use super::recording;
+
+#[cfg(feature = "highgui-recording")]
+#[inline]
+pub fn named_window(winname: &str, flags: i32) -> Option<Result<()>> {
+	recording::active().map(|recorder| recorder.named_window(winname, flags))
+}
+
+#[cfg(feature = "highgui-recording")]
+#[inline]
+pub fn imshow(winname: &str, mat: &dyn ToInputArray) -> Option<Result<()>> {
+	recording::active().map(|recorder| recorder.imshow(winname, mat))
+}
+
+#[cfg(feature = "highgui-recording")]
+#[inline]
+pub fn wait_key_ex(_delay: i32) -> Option<Result<i32>> {
+	recording::active().map(|recorder| recorder.wait_key_ex())
+}
+
+/// Like OpenCV 4 only the lowest byte of the key code is returned
+#[cfg(feature = "highgui-recording")]
+#[inline]
+pub fn wait_key(delay: i32) -> Option<Result<i32>> {
+	wait_key_ex(delay).map(|key| key.map(|key| if key == -1 { key } else { key & 0xff }))
+}
+
+#[cfg(feature = "highgui-recording")]
+#[inline]
+pub fn destroy_window(winname: &str) -> Option<Result<()>> {
+	recording::active().map(|recorder| recorder.destroy_window(winname))
+}
+
+#[cfg(feature = "highgui-recording")]
+#[inline]
+pub fn destroy_all_windows() -> Option<Result<()>> {
+	recording::active().map(|recorder| recorder.destroy_all_windows())
+}
+
+#[cfg(not(feature = "highgui-recording"))]
+#[inline(always)]
+pub fn named_window(_winname: &str, _flags: i32) -> Option<Result<()>> {
+	None
+}
+
+#[cfg(not(feature = "highgui-recording"))]
+#[inline(always)]
+pub fn imshow(_winname: &str, _mat: &dyn ToInputArray) -> Option<Result<()>> {
+	None
+}
+
+#[cfg(not(feature = "highgui-recording"))]
+#[inline(always)]
+pub fn wait_key_ex(_delay: i32) -> Option<Result<i32>> {
+	None
+}
+
+#[cfg(not(feature = "highgui-recording"))]
+#[inline(always)]
+pub fn wait_key(_delay: i32) -> Option<Result<i32>> {
+	None
+}
+
+#[cfg(not(feature = "highgui-recording"))]
+#[inline(always)]
+pub fn destroy_window(_winname: &str) -> Option<Result<()>> {
+	None
+}
+
+#[cfg(not(feature = "highgui-recording"))]
+#[inline(always)]
+pub fn destroy_all_windows() -> Option<Result<()>> {
+	None
+}
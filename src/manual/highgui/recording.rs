@@ -0,0 +1,239 @@
+use std::{
+	cell::RefCell,
+	collections::VecDeque,
+	ffi::c_void,
+	rc::{Rc, Weak},
+};
+
+use crate::{
+	core::{self, Mat, Point, ToInputArray},
+	Error,
+	highgui::{KeyCode, MouseEventTypes, MouseFlags},
+	prelude::*,
+	Result,
+};
+
+use super::backend::{MouseTrampoline, TrackbarTrampoline};
+
+thread_local! {
+	static ACTIVE: RefCell<Weak<RefCell<State>>> = const { RefCell::new(Weak::new()) };
+}
+
+pub(crate) fn active() -> Option<RecorderHandle> {
+	ACTIVE.with(|active| {
+		let active = active.borrow();
+		active.upgrade().map(|_| RecorderHandle(Weak::clone(&active)))
+	})
+}
+
+/// Image passed to `Window::imshow()` while the `Recorder` was installed
+#[derive(Debug)]
+pub struct ShownFrame {
+	/// Name of the window the image was shown in
+	pub window: String,
+	/// Copy of the image at the time of the call
+	pub mat: Mat,
+}
+
+struct RecordedTrackbar {
+	name: String,
+	pos: i32,
+	count: i32,
+	on_change: TrackbarTrampoline,
+	userdata: *mut c_void,
+}
+
+struct RecordedWindow {
+	name: String,
+	mouse: Option<(MouseTrampoline, *mut c_void)>,
+	trackbars: Vec<RecordedTrackbar>,
+}
+
+enum Input {
+	Key(i32),
+	Mouse { window: String, event: i32, pos: Point, flags: i32 },
+	Trackbar { window: String, name: String, pos: i32 },
+}
+
+#[derive(Default)]
+struct State {
+	windows: Vec<RecordedWindow>,
+	frames: Vec<ShownFrame>,
+	input: VecDeque<Input>,
+}
+
+impl State {
+	fn window(&mut self, name: &str) -> Result<&mut RecordedWindow> {
+		self.windows.iter_mut()
+			.find(|window| window.name == name)
+			.ok_or_else(|| Error::new(core::StsNullPtr, format!("Window: {} doesn't exist", name)))
+	}
+
+	fn window_or_insert(&mut self, name: &str) -> &mut RecordedWindow {
+		if let Some(i) = self.windows.iter().position(|window| window.name == name) {
+			&mut self.windows[i]
+		} else {
+			self.windows.push(RecordedWindow { name: name.to_string(), mouse: None, trackbars: vec![] });
+			self.windows.last_mut().expect("Just pushed")
+		}
+	}
+
+	fn trackbar(&mut self, name: &str, winname: &str) -> Result<&mut RecordedTrackbar> {
+		self.window(winname)?.trackbars.iter_mut()
+			.find(|trackbar| trackbar.name == name)
+			.ok_or_else(|| Error::new(core::StsNullPtr, format!("Trackbar: {} doesn't exist in window: {}", name, winname)))
+	}
+}
+
+/// Reference to the `Recorder` held by the windows that were created while it was installed
+pub(crate) struct RecorderHandle(Weak<RefCell<State>>);
+
+impl RecorderHandle {
+	fn state(&self) -> Result<Rc<RefCell<State>>> {
+		self.0.upgrade()
+			.ok_or_else(|| Error::new(core::StsError, "Recorder that the window was created with is dropped".to_string()))
+	}
+
+	pub fn named_window(&self, name: &str, _flags: i32) -> Result<()> {
+		self.state()?.borrow_mut().window_or_insert(name);
+		Ok(())
+	}
+
+	pub fn set_mouse_callback(&self, name: &str, on_mouse: MouseTrampoline, userdata: *mut c_void) -> Result<()> {
+		self.state()?.borrow_mut().window(name)?.mouse = Some((on_mouse, userdata));
+		Ok(())
+	}
+
+	pub fn create_trackbar(&self, name: &str, winname: &str, count: i32, on_change: TrackbarTrampoline, userdata: *mut c_void) -> Result<()> {
+		let state = self.state()?;
+		let mut state = state.borrow_mut();
+		let window = state.window(winname)?;
+		window.trackbars.retain(|trackbar| trackbar.name != name);
+		window.trackbars.push(RecordedTrackbar { name: name.to_string(), pos: 0, count, on_change, userdata });
+		Ok(())
+	}
+
+	pub fn imshow(&self, winname: &str, mat: &dyn ToInputArray) -> Result<()> {
+		let mat = mat.input_array()?.get_mat(-1)?.try_clone()?;
+		let state = self.state()?;
+		let mut state = state.borrow_mut();
+		// like in OpenCV the window is created if it doesn't exist
+		state.window_or_insert(winname);
+		state.frames.push(ShownFrame { window: winname.to_string(), mat });
+		Ok(())
+	}
+
+	pub fn get_trackbar_pos(&self, name: &str, winname: &str) -> Result<i32> {
+		self.state()?.borrow_mut().trackbar(name, winname).map(|trackbar| trackbar.pos)
+	}
+
+	pub fn set_trackbar_pos(&self, name: &str, winname: &str, pos: i32) -> Result<()> {
+		let (pos, on_change, userdata) = {
+			let state = self.state()?;
+			let mut state = state.borrow_mut();
+			let trackbar = state.trackbar(name, winname)?;
+			trackbar.pos = pos.max(0).min(trackbar.count);
+			(trackbar.pos, trackbar.on_change, trackbar.userdata)
+		};
+		// the state must not be borrowed during the callback because it can call back into the recorder
+		unsafe { on_change(pos, userdata) };
+		Ok(())
+	}
+
+	/// Dispatches the scripted input up to and including the next key press, returns -1 if there are no more keys
+	pub fn wait_key_ex(&self) -> Result<i32> {
+		loop {
+			let input = self.state()?.borrow_mut().input.pop_front();
+			match input {
+				None => return Ok(-1),
+				Some(Input::Key(key)) => return Ok(key),
+				Some(Input::Mouse { window, event, pos, flags }) => {
+					let mouse = self.state()?.borrow_mut().window(&window).ok().and_then(|window| window.mouse);
+					if let Some((on_mouse, userdata)) = mouse {
+						unsafe { on_mouse(event, pos.x, pos.y, flags, userdata) };
+					}
+				}
+				Some(Input::Trackbar { window, name, pos }) => {
+					// input for the windows or trackbars that don't exist is lost like with the real GUI
+					let _ = self.set_trackbar_pos(&name, &window, pos);
+				}
+			}
+		}
+	}
+
+	pub fn destroy_window(&self, name: &str) -> Result<()> {
+		self.state()?.borrow_mut().windows.retain(|window| window.name != name);
+		Ok(())
+	}
+
+	pub fn destroy_all_windows(&self) -> Result<()> {
+		self.state()?.borrow_mut().windows.clear();
+		Ok(())
+	}
+}
+
+/// In-memory GUI backend for `Window`, requires `highgui-recording` feature
+///
+/// While the `Recorder` is installed, the `Window`s created on the same thread don't use the OpenCV GUI. The shown
+/// images are stored for later inspection and the user input is taken from the script filled with `press_key()`,
+/// `mouse_event()` and `move_trackbar()`. Each `Window::poll_events()` call delivers the scripted input up to and
+/// including the next key press and returns immediately when the script is exhausted.
+///
+/// The free functions `named_window()`, `imshow()`, `wait_key()`, `wait_key_ex()`, `destroy_window()` and
+/// `destroy_all_windows()` called on the same thread are routed to the recorder too, so the existing code using them
+/// runs without a display. Mouse callbacks and trackbars can only be recorded through `Window`, the free
+/// `set_mouse_callback()` and `create_trackbar()` still call into OpenCV.
+pub struct Recorder {
+	state: Rc<RefCell<State>>,
+}
+
+impl Recorder {
+	/// Installs the recorder for the current thread, fails if there is one installed already
+	pub fn install() -> Result<Self> {
+		ACTIVE.with(|active| {
+			let mut active = active.borrow_mut();
+			if active.upgrade().is_some() {
+				return Err(Error::new(core::StsError, "Recorder is already installed for the current thread".to_string()));
+			}
+			let state = Rc::new(RefCell::new(State::default()));
+			*active = Rc::downgrade(&state);
+			Ok(Self { state })
+		})
+	}
+
+	/// Adds a key press to the input script
+	pub fn press_key(&self, key: impl Into<KeyCode>) {
+		self.state.borrow_mut().input.push_back(Input::Key(key.into().code()));
+	}
+
+	/// Adds a mouse event in the window `window` to the input script
+	pub fn mouse_event(&self, window: &str, kind: MouseEventTypes, pos: Point, flags: MouseFlags) {
+		self.state.borrow_mut().input.push_back(Input::Mouse { window: window.to_string(), event: kind as i32, pos, flags: flags.bits() });
+	}
+
+	/// Adds a trackbar movement to the input script
+	pub fn move_trackbar(&self, window: &str, name: &str, pos: i32) {
+		self.state.borrow_mut().input.push_back(Input::Trackbar { window: window.to_string(), name: name.to_string(), pos });
+	}
+
+	/// Names of the currently open windows in the order of creation
+	pub fn windows(&self) -> Vec<String> {
+		self.state.borrow().windows.iter().map(|window| window.name.clone()).collect()
+	}
+
+	/// Number of recorded frames that were not yet taken with `take_frames()`
+	pub fn frame_count(&self) -> usize {
+		self.state.borrow().frames.len()
+	}
+
+	/// Returns the frames shown so far and clears the recording
+	pub fn take_frames(&self) -> Vec<ShownFrame> {
+		self.state.borrow_mut().frames.drain(..).collect()
+	}
+}
+
+impl Drop for Recorder {
+	fn drop(&mut self) {
+		let _ = ACTIVE.try_with(|active| *active.borrow_mut() = Weak::new());
+	}
+}
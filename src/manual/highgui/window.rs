@@ -0,0 +1,237 @@
+use std::{
+	any::Any,
+	cell::RefCell,
+	ffi::c_void,
+	marker::PhantomData,
+	panic::{self, AssertUnwindSafe},
+	sync::mpsc::{self, Receiver, Sender},
+};
+
+use crate::{
+	core::{self, Point, ToInputArray},
+	Error,
+	highgui::{Event, KeyCode, MouseEventTypes, MouseFlags},
+	Result,
+};
+
+use super::{backend::Backend, event::mouse_event_type};
+
+type MouseCallbackFn = Box<dyn FnMut(MouseEventTypes, Point, MouseFlags) + Send>;
+type TrackbarCallbackFn = Box<dyn FnMut(i32) + Send>;
+
+struct MouseHandler {
+	sender: Sender<Event>,
+	callback: Option<MouseCallbackFn>,
+}
+
+impl MouseHandler {
+	fn handle(&mut self, event: i32, x: i32, y: i32, flags: i32) {
+		if let Some(kind) = mouse_event_type(event) {
+			let pos = Point::new(x, y);
+			let flags = MouseFlags::from_bits(flags);
+			if let Some(callback) = &mut self.callback {
+				callback(kind, pos, flags);
+			}
+			let _ = self.sender.send(Event::Mouse { kind, pos, flags });
+		}
+	}
+}
+
+struct TrackbarHandler {
+	name: String,
+	sender: Sender<Event>,
+	callback: Option<TrackbarCallbackFn>,
+	/// Disabled while the trackbar is being set up to not report the initial position as a change
+	enabled: bool,
+}
+
+impl TrackbarHandler {
+	fn handle(&mut self, pos: i32) {
+		if self.enabled {
+			if let Some(callback) = &mut self.callback {
+				callback(pos);
+			}
+			let _ = self.sender.send(Event::Trackbar { name: self.name.clone(), pos });
+		}
+	}
+}
+
+thread_local! {
+	/// Panic caught in a callback, it's resumed by the `Window` method that triggered the callback after returning from
+	/// OpenCV
+	static CALLBACK_PANIC: RefCell<Option<Box<dyn Any + Send>>> = RefCell::new(None);
+}
+
+/// Unwinding out of an `extern "C"` function is undefined behavior, so the panic is stored instead
+fn catch_callback_panic(f: impl FnOnce()) {
+	if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(f)) {
+		CALLBACK_PANIC.with(|panic| {
+			// the first panic is the interesting one, callbacks called after it are likely affected by it
+			panic.borrow_mut().get_or_insert(payload);
+		});
+	}
+}
+
+/// Resumes the panic caught in a callback during the OpenCV call that returned `res`
+fn resume_callback_panic<T>(res: Result<T>) -> Result<T> {
+	if let Some(payload) = CALLBACK_PANIC.with(|panic| panic.borrow_mut().take()) {
+		panic::resume_unwind(payload);
+	}
+	res
+}
+
+unsafe extern "C" fn mouse_trampoline(event: i32, x: i32, y: i32, flags: i32, userdata: *mut c_void) {
+	catch_callback_panic(|| (*(userdata as *mut MouseHandler)).handle(event, x, y, flags))
+}
+
+unsafe extern "C" fn trackbar_trampoline(pos: i32, userdata: *mut c_void) {
+	catch_callback_panic(|| (*(userdata as *mut TrackbarHandler)).handle(pos))
+}
+
+/// HighGUI window that owns its callbacks and reports the user input as `Event`s
+///
+/// Events are delivered during `poll_events()`, either through the internal queue (returned from `poll_events()`) or
+/// through the channel passed to `Window::with_sender()`. The window is destroyed together with its callbacks when it's
+/// dropped. If a `Recorder` is installed on the current thread when the window is created, all its calls go to the
+/// recorder instead of the OpenCV GUI.
+///
+/// `Window` is neither `Send` nor `Sync` regardless of the enabled features: polling the events of any window runs the
+/// callbacks of all windows on the polling thread, so all windows must stay on the thread they were created on.
+pub struct Window {
+	backend: Backend,
+	name: String,
+	sender: Sender<Event>,
+	receiver: Option<Receiver<Event>>,
+	// boxed so that the addresses passed to OpenCV as userdata stay stable
+	mouse: Box<MouseHandler>,
+	#[allow(clippy::vec_box)]
+	trackbars: Vec<Box<TrackbarHandler>>,
+	destroyed: bool,
+	_not_send: PhantomData<*const ()>,
+}
+
+impl Window {
+	/// Creates a window, `flags` are the `WINDOW_*` constants like for `named_window()`
+	pub fn new(name: &str, flags: i32) -> Result<Self> {
+		let (sender, receiver) = mpsc::channel();
+		Self::create(name, flags, sender, Some(receiver))
+	}
+
+	/// Creates a window that sends its events to `sender` instead of returning them from `poll_events()`
+	pub fn with_sender(name: &str, flags: i32, sender: Sender<Event>) -> Result<Self> {
+		Self::create(name, flags, sender, None)
+	}
+
+	fn create(name: &str, flags: i32, sender: Sender<Event>, receiver: Option<Receiver<Event>>) -> Result<Self> {
+		let backend = Backend::current();
+		backend.named_window(name, flags)?;
+		let mut out = Self {
+			backend,
+			name: name.to_string(),
+			mouse: Box::new(MouseHandler { sender: sender.clone(), callback: None }),
+			sender,
+			receiver,
+			trackbars: vec![],
+			destroyed: false,
+			_not_send: PhantomData,
+		};
+		let userdata = out.mouse.as_mut() as *mut MouseHandler as *mut c_void;
+		out.backend.set_mouse_callback(&out.name, mouse_trampoline, userdata)?;
+		Ok(out)
+	}
+
+	#[inline]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Displays the image in the window, see `imshow()`
+	#[inline]
+	pub fn imshow(&self, mat: &dyn ToInputArray) -> Result<()> {
+		self.backend.imshow(&self.name, mat)
+	}
+
+	/// Sets the closure that is called for every mouse event in addition to sending `Event::Mouse`, replaces the
+	/// previous one
+	pub fn set_mouse_callback(&mut self, callback: impl FnMut(MouseEventTypes, Point, MouseFlags) + Send + 'static) {
+		self.mouse.callback = Some(Box::new(callback));
+	}
+
+	/// Removes the closure set by `set_mouse_callback()`, `Event::Mouse` is still sent
+	pub fn remove_mouse_callback(&mut self) {
+		self.mouse.callback = None;
+	}
+
+	/// Creates a trackbar with the range `0..=count` and initial position `value`, changes are reported as
+	/// `Event::Trackbar`
+	#[inline]
+	pub fn create_trackbar(&mut self, name: &str, value: i32, count: i32) -> Result<()> {
+		self.add_trackbar(name, value, count, None)
+	}
+
+	/// Same as `create_trackbar()`, but additionally calls `on_change` with the new position
+	#[inline]
+	pub fn create_trackbar_with_callback(&mut self, name: &str, value: i32, count: i32, on_change: impl FnMut(i32) + Send + 'static) -> Result<()> {
+		self.add_trackbar(name, value, count, Some(Box::new(on_change)))
+	}
+
+	fn add_trackbar(&mut self, name: &str, value: i32, count: i32, callback: Option<TrackbarCallbackFn>) -> Result<()> {
+		if self.trackbars.iter().any(|trackbar| trackbar.name == name) {
+			return Err(Error::new(core::StsBadArg, format!("Trackbar: {} already exists in window: {}", name, self.name)));
+		}
+		let mut handler = Box::new(TrackbarHandler { name: name.to_string(), sender: self.sender.clone(), callback, enabled: false });
+		let userdata = handler.as_mut() as *mut TrackbarHandler as *mut c_void;
+		// push before registering so that the handler outlives the trackbar, it's only removed if the trackbar wasn't
+		// created
+		self.trackbars.push(handler);
+		if let Err(e) = self.backend.create_trackbar(name, &self.name, count, trackbar_trampoline, userdata) {
+			self.trackbars.pop();
+			return Err(e);
+		}
+		let res = self.backend.set_trackbar_pos(name, &self.name, value);
+		if let Some(handler) = self.trackbars.last_mut() {
+			handler.enabled = true;
+		}
+		resume_callback_panic(res)
+	}
+
+	/// Current position of the trackbar, see `get_trackbar_pos()`
+	#[inline]
+	pub fn trackbar_pos(&self, name: &str) -> Result<i32> {
+		self.backend.get_trackbar_pos(name, &self.name)
+	}
+
+	/// Sets the position of the trackbar, see `set_trackbar_pos()`
+	#[inline]
+	pub fn set_trackbar_pos(&self, name: &str, pos: i32) -> Result<()> {
+		resume_callback_panic(self.backend.set_trackbar_pos(name, &self.name, pos))
+	}
+
+	/// Processes the GUI events for up to `delay` milliseconds (0 means forever) and returns the received events
+	///
+	/// Like `wait_key_ex()` this processes the events of all windows, the pressed key is reported as `Event::Key` by
+	/// the window that was polled. Returns an empty `Vec` if the window was created with `Window::with_sender()`.
+	///
+	/// If a callback panics the panic is resumed from here once the control returns from OpenCV.
+	pub fn poll_events(&self, delay: i32) -> Result<Vec<Event>> {
+		let key = resume_callback_panic(self.backend.wait_key_ex(delay))?;
+		if key != -1 {
+			let _ = self.sender.send(Event::Key(KeyCode::from_code(key)));
+		}
+		Ok(self.receiver.as_ref().map_or_else(Vec::new, |receiver| receiver.try_iter().collect()))
+	}
+
+	/// Destroys the window and its callbacks, errors are ignored when the window is just dropped
+	pub fn destroy(mut self) -> Result<()> {
+		self.destroyed = true;
+		self.backend.destroy_window(&self.name)
+	}
+}
+
+impl Drop for Window {
+	fn drop(&mut self) {
+		if !self.destroyed {
+			let _ = self.backend.destroy_window(&self.name);
+		}
+	}
+}
@@ -0,0 +1,9 @@
+pub use codec::*;
+pub use flags::*;
+pub use pages::*;
+pub use params::*;
+
+mod codec;
+mod flags;
+mod pages;
+mod params;
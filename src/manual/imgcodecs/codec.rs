@@ -0,0 +1,91 @@
+use crate::{
+	core::{self, Mat, MatRef, Vector},
+	Error,
+	imgcodecs::{self, ImreadFlags, JpegWriteParams, PngWriteParams, WebpWriteParams, WriteParams},
+	prelude::*,
+	Result,
+};
+#[cfg(not(ocvrs_opencv_branch_32))]
+use crate::imgcodecs::{ExrWriteParams, TiffWriteParams};
+
+/// Type of the pixels stored in the OpenEXR image
+#[cfg(not(ocvrs_opencv_branch_32))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExrType {
+	/// 16-bit floating point
+	Half,
+	/// 32-bit floating point
+	Float,
+}
+
+/// Output format with its encoding options for `encode()`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ImageFormat {
+	/// `compression` is the zlib compression level from 0 to 9, higher values mean smaller output and longer
+	/// compression time
+	Png { compression: i32 },
+	/// `quality` is from 0 to 100, higher is better
+	Jpeg { quality: i32, progressive: bool },
+	/// `quality` is from 1 to 100, 101 selects the lossless compression
+	Webp { quality: i32 },
+	/// `compression` is the libtiff compression scheme, e.g. 1 is none, 5 is LZW
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	Tiff { compression: i32 },
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	Exr { typ: ExrType },
+}
+
+impl ImageFormat {
+	/// File extension that selects the encoder in OpenCV, including the leading dot
+	pub fn extension(&self) -> &'static str {
+		match self {
+			Self::Png { .. } => ".png",
+			Self::Jpeg { .. } => ".jpg",
+			Self::Webp { .. } => ".webp",
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			Self::Tiff { .. } => ".tiff",
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			Self::Exr { .. } => ".exr",
+		}
+	}
+
+	/// Validates the options and builds the `IMWRITE_*` key/value pairs for the encoder, see `WriteParams`
+	pub fn params(&self) -> Result<Vector<i32>> {
+		match *self {
+			Self::Png { compression } => PngWriteParams { compression: Some(compression), ..PngWriteParams::default() }.to_params(),
+			Self::Jpeg { quality, progressive } => JpegWriteParams { quality: Some(quality), progressive: Some(progressive), ..JpegWriteParams::default() }.to_params(),
+			Self::Webp { quality } => WebpWriteParams { quality: Some(quality) }.to_params(),
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			Self::Tiff { compression } => TiffWriteParams { compression: Some(compression), ..TiffWriteParams::default() }.to_params(),
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			Self::Exr { typ } => ExrWriteParams { typ: Some(typ) }.to_params(),
+		}
+	}
+}
+
+/// Decodes the image from the encoded bytes in memory, e.g. contents of a PNG or JPEG file
+///
+/// Unlike `imdecode()` it returns an error instead of an empty `Mat` if the data can't be decoded.
+pub fn decode(buf: &[u8], flags: ImreadFlags) -> Result<Mat> {
+	if buf.is_empty() {
+		return Err(Error::new(core::StsBadArg, "Can't decode image from empty buffer".to_string()));
+	}
+	let src = MatRef::from_slice(buf)?;
+	let out = imgcodecs::imdecode(&src, flags.bits())?;
+	if out.empty()? {
+		Err(Error::new(core::StsError, format!("Can't decode image from buffer of {} bytes, unknown or corrupted format", buf.len())))
+	} else {
+		Ok(out)
+	}
+}
+
+/// Encodes the image into the in-memory representation of the specified `format`
+pub fn encode(img: &Mat, format: ImageFormat) -> Result<Vec<u8>> {
+	let params = format.params()?;
+	let mut buf = Vector::new();
+	if imgcodecs::imencode(format.extension(), img, &mut buf, &params)? {
+		Ok(buf.to_vec())
+	} else {
+		Err(Error::new(core::StsError, format!("Can't encode image as: {}", format.extension())))
+	}
+}
@@ -0,0 +1,107 @@
+use std::ops::{BitOr, BitOrAssign};
+
+use crate::{
+	core::{self, Mat, Vector},
+	imgcodecs::{self, ImreadModes},
+	Result,
+};
+
+/// Flags for reading and decoding the images, wrapper for `IMREAD_*` constants
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ImreadFlags(i32);
+
+impl ImreadFlags {
+	pub const UNCHANGED: Self = Self(imgcodecs::IMREAD_UNCHANGED);
+	pub const GRAYSCALE: Self = Self(imgcodecs::IMREAD_GRAYSCALE);
+	pub const COLOR: Self = Self(imgcodecs::IMREAD_COLOR);
+	pub const ANYDEPTH: Self = Self(imgcodecs::IMREAD_ANYDEPTH);
+	pub const ANYCOLOR: Self = Self(imgcodecs::IMREAD_ANYCOLOR);
+	pub const LOAD_GDAL: Self = Self(imgcodecs::IMREAD_LOAD_GDAL);
+	pub const REDUCED_GRAYSCALE_2: Self = Self(imgcodecs::IMREAD_REDUCED_GRAYSCALE_2);
+	pub const REDUCED_COLOR_2: Self = Self(imgcodecs::IMREAD_REDUCED_COLOR_2);
+	pub const REDUCED_GRAYSCALE_4: Self = Self(imgcodecs::IMREAD_REDUCED_GRAYSCALE_4);
+	pub const REDUCED_COLOR_4: Self = Self(imgcodecs::IMREAD_REDUCED_COLOR_4);
+	pub const REDUCED_GRAYSCALE_8: Self = Self(imgcodecs::IMREAD_REDUCED_GRAYSCALE_8);
+	pub const REDUCED_COLOR_8: Self = Self(imgcodecs::IMREAD_REDUCED_COLOR_8);
+	pub const IGNORE_ORIENTATION: Self = Self(imgcodecs::IMREAD_IGNORE_ORIENTATION);
+
+	/// Raw value suitable for the `flags` argument of `imread()`, `imdecode()` etc.
+	#[inline]
+	pub const fn bits(self) -> i32 {
+		self.0
+	}
+
+	/// Creates flags from the raw value, no validation is performed
+	#[inline]
+	pub const fn from_bits(bits: i32) -> Self {
+		Self(bits)
+	}
+
+	/// Checks whether all bits of `other` are set in `self`
+	///
+	/// `UNCHANGED` (-1) and `GRAYSCALE` (0) are not bit flags, they are only contained in the equal value and
+	/// `UNCHANGED` contains no other flags.
+	#[inline]
+	pub const fn contains(self, other: Self) -> bool {
+		match other.0 {
+			imgcodecs::IMREAD_UNCHANGED | imgcodecs::IMREAD_GRAYSCALE => self.0 == other.0,
+			_ => self.0 != imgcodecs::IMREAD_UNCHANGED && self.0 & other.0 == other.0,
+		}
+	}
+}
+
+impl BitOr for ImreadFlags {
+	type Output = Self;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self {
+		Self(self.0 | rhs.0)
+	}
+}
+
+impl BitOrAssign for ImreadFlags {
+	#[inline]
+	fn bitor_assign(&mut self, rhs: Self) {
+		self.0 |= rhs.0;
+	}
+}
+
+impl From<ImreadModes> for ImreadFlags {
+	#[inline]
+	fn from(mode: ImreadModes) -> Self {
+		Self(mode as i32)
+	}
+}
+
+impl From<ImreadFlags> for i32 {
+	#[inline]
+	fn from(flags: ImreadFlags) -> Self {
+		flags.0
+	}
+}
+
+impl Default for ImreadFlags {
+	/// `COLOR`, same as the default of `imread()`
+	#[inline]
+	fn default() -> Self {
+		Self::COLOR
+	}
+}
+
+/// Same as `imread()`, but takes typed flags
+#[inline]
+pub fn imread_flags(filename: &str, flags: ImreadFlags) -> Result<Mat> {
+	imgcodecs::imread(filename, flags.bits())
+}
+
+/// Same as `imreadmulti()`, but takes typed flags
+#[inline]
+pub fn imreadmulti_flags(filename: &str, mats: &mut Vector<Mat>, flags: ImreadFlags) -> Result<bool> {
+	imgcodecs::imreadmulti(filename, mats, flags.bits())
+}
+
+/// Same as `imdecode()`, but takes typed flags
+#[inline]
+pub fn imdecode_flags(buf: &dyn core::ToInputArray, flags: ImreadFlags) -> Result<Mat> {
+	imgcodecs::imdecode(buf, flags.bits())
+}
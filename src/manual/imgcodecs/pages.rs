@@ -0,0 +1,130 @@
+use std::{
+	ffi::{c_void, CString},
+	iter::FusedIterator,
+	os::raw::c_char,
+	path::{Path, PathBuf},
+};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	imgcodecs::ImreadFlags,
+	prelude::*,
+	Result,
+	sys,
+};
+
+/// Single decoded page of a multi-page image
+#[derive(Debug)]
+pub struct Page {
+	pub mat: Mat,
+	/// Zero-based index of the page in the file
+	pub index: usize,
+}
+
+/// Iterator over the pages of a multi-page image like TIFF, see `pages()`
+///
+/// Only the page that is currently being decoded is kept in memory, the iteration ends after the last page or on the
+/// first error. Only the number of pages is known up front, the size and type of a page are only available after it's
+/// decoded because OpenCV doesn't expose the header reading of its decoders.
+pub struct Pages {
+	ptr: *mut c_void,
+	path: PathBuf,
+	page_count: usize,
+	next: usize,
+}
+
+impl Pages {
+	/// Total number of pages in the file, known before any page is decoded unlike the other page metadata
+	#[inline]
+	pub fn page_count(&self) -> usize {
+		self.page_count
+	}
+
+	#[inline]
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	/// Decodes the page at `index` without affecting the iteration
+	///
+	/// Going back to the earlier pages can be slow because the decoder has to restart from the beginning of the file.
+	pub fn get(&mut self, index: usize) -> Result<Mat> {
+		if index >= self.page_count {
+			return Err(Error::new(core::StsOutOfRange, format!("Page index: {} is out of range, file: {} has {} pages", index, self.path.display(), self.page_count)));
+		}
+		unsafe { cv_manual_ImagePages_read(self.ptr, index) }
+			.into_result()
+			.map(|ptr| unsafe { Mat::from_raw(ptr) })
+	}
+}
+
+impl Iterator for Pages {
+	type Item = Result<Page>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.next >= self.page_count {
+			return None;
+		}
+		let index = self.next;
+		match self.get(index) {
+			Ok(mat) => {
+				self.next += 1;
+				Some(Ok(Page { mat, index }))
+			}
+			Err(e) => {
+				self.next = self.page_count;
+				Some(Err(e))
+			}
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.page_count - self.next))
+	}
+}
+
+impl FusedIterator for Pages {}
+
+impl Drop for Pages {
+	fn drop(&mut self) {
+		unsafe { cv_manual_ImagePages_delete(self.ptr) };
+	}
+}
+
+// SAFETY: `ptr` is uniquely owned by `Pages` and only accessed through `&mut self` or in `drop()`, the decoder it wraps
+// (`ImageCollection` or the file name for `imreadmulti()`) has no thread affinity. It's not a generated class so the
+// generator Send/Sync policy doesn't apply.
+unsafe impl Send for Pages {}
+
+/// Opens a multi-page image for reading the pages one by one, pages are decoded with `ImreadFlags::default()` (`COLOR`)
+/// like in `imread()`, use `pages_with_flags()` to get the `ANYCOLOR` default of `imreadmulti()`
+///
+/// Unlike `imreadmulti()` this doesn't decode all pages into memory at once so it's suitable for very large files.
+/// Requires OpenCV 4.5.5 or newer, with the older versions it returns `StsNotImplemented` error.
+#[inline]
+pub fn pages(path: impl AsRef<Path>) -> Result<Pages> {
+	pages_with_flags(path, ImreadFlags::default())
+}
+
+/// Same as `pages()`, but with the specified flags for decoding
+pub fn pages_with_flags(path: impl AsRef<Path>, flags: ImreadFlags) -> Result<Pages> {
+	let path = path.as_ref();
+	let filename = path.to_str()
+		.ok_or_else(|| Error::new(core::StsBadArg, format!("Path is not valid UTF-8: {}", path.display())))?;
+	let filename = CString::new(filename)?;
+	let ptr = unsafe { cv_manual_ImagePages_new(filename.as_ptr(), flags.bits()) }.into_result()?;
+	let mut out = Pages { ptr, path: path.to_path_buf(), page_count: 0, next: 0 };
+	out.page_count = unsafe { cv_manual_ImagePages_size(out.ptr) }.into_result()?;
+	if out.page_count == 0 {
+		return Err(Error::new(core::StsError, format!("Can't read pages from: {}, the file doesn't exist or its format is not supported", path.display())));
+	}
+	Ok(out)
+}
+
+extern "C" {
+	fn cv_manual_ImagePages_new(filename: *const c_char, flags: i32) -> sys::Result<*mut c_void>;
+	fn cv_manual_ImagePages_delete(instance: *mut c_void);
+	fn cv_manual_ImagePages_size(instance: *const c_void) -> sys::Result<usize>;
+	fn cv_manual_ImagePages_read(instance: *mut c_void, index: usize) -> sys::Result<*mut c_void>;
+}
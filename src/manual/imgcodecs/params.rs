@@ -0,0 +1,189 @@
+use crate::{
+	core::{self, ToInputArray, Vector},
+	Error,
+	imgcodecs::{self, ImwritePAMFlags, ImwritePNGFlags},
+	Result,
+};
+#[cfg(not(ocvrs_opencv_branch_32))]
+use crate::imgcodecs::ExrType;
+
+/// Typed encoder options that can be converted to the `params` argument of `imwrite()` and `imencode()`
+///
+/// Only the options that are set are passed to the encoder, the rest keep the OpenCV defaults.
+pub trait WriteParams {
+	/// Validates the options and builds the `IMWRITE_*` key/value pairs
+	fn to_params(&self) -> Result<Vector<i32>>;
+}
+
+fn push_param<T: Into<i32>>(params: &mut Vec<i32>, key: i32, value: Option<T>) {
+	if let Some(value) = value {
+		params.push(key);
+		params.push(value.into());
+	}
+}
+
+/// Same as `push_param()`, but fails if the `value` is outside of `min..=max`
+fn push_param_checked(params: &mut Vec<i32>, name: &str, key: i32, value: Option<i32>, min: i32, max: i32) -> Result<()> {
+	if let Some(value) = value {
+		if !(min..=max).contains(&value) {
+			return Err(Error::new(core::StsOutOfRange, format!("{} is: {}, but it must be in range: {}..={}", name, value, min, max)));
+		}
+	}
+	push_param(params, key, value);
+	Ok(())
+}
+
+/// Options for the JPEG encoder
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct JpegWriteParams {
+	/// From 0 to 100, higher is better, `IMWRITE_JPEG_QUALITY`
+	pub quality: Option<i32>,
+	/// `IMWRITE_JPEG_PROGRESSIVE`
+	pub progressive: Option<bool>,
+	/// Optimize the Huffman tables, `IMWRITE_JPEG_OPTIMIZE`
+	pub optimize: Option<bool>,
+	/// From 0 to 65535, 0 means no restart markers, `IMWRITE_JPEG_RST_INTERVAL`
+	pub restart_interval: Option<i32>,
+	/// Separate luma quality from 0 to 100, `IMWRITE_JPEG_LUMA_QUALITY`
+	pub luma_quality: Option<i32>,
+	/// Separate chroma quality from 0 to 100, `IMWRITE_JPEG_CHROMA_QUALITY`
+	pub chroma_quality: Option<i32>,
+}
+
+impl WriteParams for JpegWriteParams {
+	fn to_params(&self) -> Result<Vector<i32>> {
+		let mut out = vec![];
+		push_param_checked(&mut out, "JPEG quality", imgcodecs::IMWRITE_JPEG_QUALITY, self.quality, 0, 100)?;
+		push_param(&mut out, imgcodecs::IMWRITE_JPEG_PROGRESSIVE, self.progressive);
+		push_param(&mut out, imgcodecs::IMWRITE_JPEG_OPTIMIZE, self.optimize);
+		push_param_checked(&mut out, "JPEG restart interval", imgcodecs::IMWRITE_JPEG_RST_INTERVAL, self.restart_interval, 0, 65535)?;
+		push_param_checked(&mut out, "JPEG luma quality", imgcodecs::IMWRITE_JPEG_LUMA_QUALITY, self.luma_quality, 0, 100)?;
+		push_param_checked(&mut out, "JPEG chroma quality", imgcodecs::IMWRITE_JPEG_CHROMA_QUALITY, self.chroma_quality, 0, 100)?;
+		Ok(Vector::from(out))
+	}
+}
+
+/// Options for the PNG encoder
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PngWriteParams {
+	/// zlib compression level from 0 to 9, `IMWRITE_PNG_COMPRESSION`
+	pub compression: Option<i32>,
+	/// `IMWRITE_PNG_STRATEGY`
+	pub strategy: Option<ImwritePNGFlags>,
+	/// Write 1-bit image, `IMWRITE_PNG_BILEVEL`
+	pub bilevel: Option<bool>,
+}
+
+impl WriteParams for PngWriteParams {
+	fn to_params(&self) -> Result<Vector<i32>> {
+		let mut out = vec![];
+		push_param_checked(&mut out, "PNG compression", imgcodecs::IMWRITE_PNG_COMPRESSION, self.compression, 0, 9)?;
+		push_param(&mut out, imgcodecs::IMWRITE_PNG_STRATEGY, self.strategy.map(|strategy| strategy as i32));
+		push_param(&mut out, imgcodecs::IMWRITE_PNG_BILEVEL, self.bilevel);
+		Ok(Vector::from(out))
+	}
+}
+
+/// Options for the WebP encoder
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WebpWriteParams {
+	/// From 1 to 100 for the lossy compression, 101 selects the lossless compression, `IMWRITE_WEBP_QUALITY`
+	pub quality: Option<i32>,
+}
+
+impl WriteParams for WebpWriteParams {
+	fn to_params(&self) -> Result<Vector<i32>> {
+		let mut out = vec![];
+		push_param_checked(&mut out, "WebP quality", imgcodecs::IMWRITE_WEBP_QUALITY, self.quality, 1, 101)?;
+		Ok(Vector::from(out))
+	}
+}
+
+/// Options for the PBM, PGM and PPM encoders
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PxmWriteParams {
+	/// Binary or ASCII format, `IMWRITE_PXM_BINARY`
+	pub binary: Option<bool>,
+}
+
+impl WriteParams for PxmWriteParams {
+	fn to_params(&self) -> Result<Vector<i32>> {
+		let mut out = vec![];
+		push_param(&mut out, imgcodecs::IMWRITE_PXM_BINARY, self.binary);
+		Ok(Vector::from(out))
+	}
+}
+
+/// Options for the PAM encoder
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PamWriteParams {
+	/// `IMWRITE_PAM_TUPLETYPE`
+	pub tuple_type: Option<ImwritePAMFlags>,
+}
+
+impl WriteParams for PamWriteParams {
+	fn to_params(&self) -> Result<Vector<i32>> {
+		let mut out = vec![];
+		push_param(&mut out, imgcodecs::IMWRITE_PAM_TUPLETYPE, self.tuple_type.map(|tuple_type| tuple_type as i32));
+		Ok(Vector::from(out))
+	}
+}
+
+/// Options for the TIFF encoder
+#[cfg(not(ocvrs_opencv_branch_32))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TiffWriteParams {
+	/// libtiff compression scheme, e.g. 1 is none, 5 is LZW, `IMWRITE_TIFF_COMPRESSION`
+	pub compression: Option<i32>,
+	/// 1 is none, 2 is inch, 3 is centimeter, `IMWRITE_TIFF_RESUNIT`
+	pub resolution_unit: Option<i32>,
+	/// `IMWRITE_TIFF_XDPI`
+	pub x_dpi: Option<i32>,
+	/// `IMWRITE_TIFF_YDPI`
+	pub y_dpi: Option<i32>,
+}
+
+#[cfg(not(ocvrs_opencv_branch_32))]
+impl WriteParams for TiffWriteParams {
+	fn to_params(&self) -> Result<Vector<i32>> {
+		let mut out = vec![];
+		push_param_checked(&mut out, "TIFF compression", imgcodecs::IMWRITE_TIFF_COMPRESSION, self.compression, 1, i32::MAX)?;
+		push_param_checked(&mut out, "TIFF resolution unit", imgcodecs::IMWRITE_TIFF_RESUNIT, self.resolution_unit, 1, 3)?;
+		push_param_checked(&mut out, "TIFF X DPI", imgcodecs::IMWRITE_TIFF_XDPI, self.x_dpi, 1, i32::MAX)?;
+		push_param_checked(&mut out, "TIFF Y DPI", imgcodecs::IMWRITE_TIFF_YDPI, self.y_dpi, 1, i32::MAX)?;
+		Ok(Vector::from(out))
+	}
+}
+
+/// Options for the OpenEXR encoder
+#[cfg(not(ocvrs_opencv_branch_32))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExrWriteParams {
+	/// `IMWRITE_EXR_TYPE`
+	pub typ: Option<ExrType>,
+}
+
+#[cfg(not(ocvrs_opencv_branch_32))]
+impl WriteParams for ExrWriteParams {
+	fn to_params(&self) -> Result<Vector<i32>> {
+		let mut out = vec![];
+		push_param(&mut out, imgcodecs::IMWRITE_EXR_TYPE, self.typ.map(|typ| match typ {
+			ExrType::Half => imgcodecs::IMWRITE_EXR_TYPE_HALF,
+			ExrType::Float => imgcodecs::IMWRITE_EXR_TYPE_FLOAT,
+		}));
+		Ok(Vector::from(out))
+	}
+}
+
+/// Same as `imwrite()`, but takes typed encoder options
+#[inline]
+pub fn imwrite_params(filename: &str, img: &dyn ToInputArray, params: &impl WriteParams) -> Result<bool> {
+	imgcodecs::imwrite(filename, img, &params.to_params()?)
+}
+
+/// Same as `imwritemulti()`, but takes typed encoder options
+#[inline]
+pub fn imwritemulti_params(filename: &str, img: &dyn ToInputArray, params: &impl WriteParams) -> Result<bool> {
+	// imwritemulti() is an inline alias of imwrite() that is only present in the newer OpenCV versions
+	imgcodecs::imwrite(filename, img, &params.to_params()?)
+}
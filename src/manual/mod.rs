@@ -1,15 +1,25 @@
+#[cfg(ocvrs_has_module_calib3d)]
+pub mod calib3d;
 #[cfg(ocvrs_has_module_core)]
 pub mod core;
 #[cfg(ocvrs_has_module_dnn)]
 pub mod dnn;
 #[cfg(ocvrs_has_module_features2d)]
 pub mod features2d;
+#[cfg(ocvrs_has_module_highgui)]
+pub mod highgui;
+#[cfg(ocvrs_has_module_imgcodecs)]
+pub mod imgcodecs;
 pub mod sys;
 pub mod types;
+#[cfg(ocvrs_has_module_videoio)]
+pub mod videoio;
 
 pub mod prelude {
 	#[cfg(ocvrs_has_module_core)]
-	pub use super::core::{MatConstIteratorTraitManual, MatTraitManual, MatxTrait, UMatTraitManual};
+	pub use super::core::{MatCompare, MatConstIteratorTraitManual, MatTraitManual, MatxTrait, UMatTraitManual};
 	#[cfg(all(ocvrs_has_module_core, ocvrs_opencv_branch_32))]
 	pub use super::core::MatSizeTraitManual;
+	#[cfg(ocvrs_has_module_videoio)]
+	pub use super::videoio::{VideoCaptureTraitManual, VideoWriterTraitManual};
 }
@@ -7,23 +7,34 @@ use std::{
 	marker::PhantomData,
 	ffi::c_void,
 };
-use crate::{Error, Result as CrateResult, types::Unit};
+use crate::{CppLocation, Error, Result as CrateResult, templ::receive_string, types::Unit};
 
 #[repr(C)]
 pub struct Result<S, O = S> {
 	pub error_code: i32,
 	pub error_msg: *mut c_void,
+	pub error_func: *mut c_void,
+	pub error_file: *mut c_void,
+	pub error_line: i32,
 	pub result: S,
 	_p: PhantomData<O>,
 }
 
 impl<S: Into<O>, O> Result<S, O> {
 	#[inline]
+	#[track_caller]
 	pub fn into_result(self) -> CrateResult<O> {
 		if self.error_msg.is_null() {
 			Ok(self.result.into())
 		} else {
-			Err(Error::new(self.error_code, unsafe { crate::templ::receive_string(self.error_msg as *mut String) }))
+			let message = unsafe { receive_string(self.error_msg as *mut String) };
+			let func = (!self.error_func.is_null()).then(|| unsafe { receive_string(self.error_func as *mut String) });
+			let file = (!self.error_file.is_null()).then(|| unsafe { receive_string(self.error_file as *mut String) });
+			let mut out = Error::new(self.error_code, message);
+			if let (Some(func), Some(file)) = (func, file) {
+				out.cpp_location = Some(CppLocation { func, file, line: self.error_line });
+			}
+			Err(out)
 		}
 	}
 }
@@ -0,0 +1,15 @@
+pub use async_capture::*;
+#[cfg(not(ocvrs_opencv_branch_32))]
+pub use devices::*;
+pub use frames::*;
+pub use properties::*;
+#[cfg(not(ocvrs_opencv_branch_32))]
+pub use writer_builder::*;
+
+mod async_capture;
+#[cfg(not(ocvrs_opencv_branch_32))]
+mod devices;
+mod frames;
+mod properties;
+#[cfg(not(ocvrs_opencv_branch_32))]
+mod writer_builder;
@@ -0,0 +1,264 @@
+use std::{
+	collections::VecDeque,
+	sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+	thread::{self, JoinHandle},
+};
+#[cfg(feature = "futures-core")]
+use std::{
+	pin::Pin,
+	task::{Context, Poll, Waker},
+};
+
+use crate::{
+	core,
+	Error,
+	Result,
+	videoio::{Frame, VideoCapture},
+};
+
+/// What `AsyncVideoCapture` does when the frame buffer is full
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OverflowPolicy {
+	/// Discard the oldest buffered frame to make room for the new one, suitable for live sources
+	DropOldest,
+	/// Pause reading from the capture until there is room in the buffer, suitable for files
+	Block,
+}
+
+struct State {
+	frames: VecDeque<Frame>,
+	error: Option<Error>,
+	dropped: u64,
+	finished: bool,
+	stop: bool,
+	#[cfg(feature = "futures-core")]
+	waker: Option<Waker>,
+}
+
+impl State {
+	#[cfg(feature = "futures-core")]
+	fn wake(&mut self) {
+		if let Some(waker) = self.waker.take() {
+			waker.wake();
+		}
+	}
+
+	#[cfg(not(feature = "futures-core"))]
+	fn wake(&mut self) {}
+}
+
+struct Shared {
+	state: Mutex<State>,
+	capacity: usize,
+	policy: OverflowPolicy,
+	frame_available: Condvar,
+	space_available: Condvar,
+}
+
+impl Shared {
+	fn state(&self) -> MutexGuard<State> {
+		self.state.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	/// Returns false if the reader thread must stop
+	fn push(&self, frame: Frame) -> bool {
+		let mut state = self.state();
+		while state.frames.len() >= self.capacity && !state.stop {
+			match self.policy {
+				OverflowPolicy::DropOldest => {
+					state.frames.pop_front();
+					state.dropped += 1;
+				}
+				OverflowPolicy::Block => {
+					state = self.space_available.wait(state).unwrap_or_else(PoisonError::into_inner);
+				}
+			}
+		}
+		if state.stop {
+			return false;
+		}
+		state.frames.push_back(frame);
+		self.notify(state);
+		true
+	}
+
+	fn finish(&self, error: Option<Error>) {
+		let mut state = self.state();
+		state.error = error;
+		state.finished = true;
+		self.notify(state);
+	}
+
+	fn notify(&self, mut state: MutexGuard<State>) {
+		state.wake();
+		drop(state);
+		self.frame_available.notify_all();
+	}
+
+	/// Blocks until there is a frame in the buffer or the reader is finished
+	fn wait_frames(&self) -> MutexGuard<State> {
+		let mut state = self.state();
+		while state.frames.is_empty() && !state.finished {
+			state = self.frame_available.wait(state).unwrap_or_else(PoisonError::into_inner);
+		}
+		state
+	}
+
+	/// Takes a frame from the buffer using `take`, after the end of stream the reader error (if any) is returned once
+	fn take_frame(&self, mut state: MutexGuard<State>, take: impl FnOnce(&mut VecDeque<Frame>) -> Option<Frame>) -> Option<Result<Frame>> {
+		let out = take(&mut state.frames)
+			.map(Ok)
+			.or_else(|| state.error.take().map(Err));
+		drop(state);
+		self.space_available.notify_all();
+		out
+	}
+
+	fn stop(&self) {
+		self.state().stop = true;
+		self.space_available.notify_all();
+	}
+}
+
+/// Finishes the reading when the reader thread exits, including by a panic, so that the consumers don't wait forever
+struct FinishGuard {
+	shared: Arc<Shared>,
+	error: Option<Error>,
+}
+
+impl Drop for FinishGuard {
+	fn drop(&mut self) {
+		let error = if thread::panicking() {
+			Some(Error::new(core::StsError, "Reader thread panicked".to_string()))
+		} else {
+			self.error.take()
+		};
+		self.shared.finish(error);
+	}
+}
+
+/// `VideoCapture` that reads the frames on a dedicated thread into a bounded buffer
+///
+/// The reading ends when the capture can't provide the next frame or on the first error, the buffered frames are
+/// still available after that and the error is returned after them. Dropping the `AsyncVideoCapture` stops the reader
+/// thread and waits for it to exit. The stop request is only noticed between the frames, so dropping blocks until the
+/// `read()` that is in progress returns, which can take forever with a stalled live camera.
+pub struct AsyncVideoCapture {
+	shared: Arc<Shared>,
+	reader: Option<JoinHandle<VideoCapture>>,
+}
+
+impl AsyncVideoCapture {
+	/// Starts reading from `capture` on a new thread, up to `capacity` frames are buffered
+	pub fn new(mut capture: VideoCapture, capacity: usize, policy: OverflowPolicy) -> Result<Self> {
+		if capacity == 0 {
+			return Err(Error::new(core::StsBadArg, "Buffer capacity must be greater than 0".to_string()));
+		}
+		let shared = Arc::new(Shared {
+			state: Mutex::new(State {
+				frames: VecDeque::with_capacity(capacity),
+				error: None,
+				dropped: 0,
+				finished: false,
+				stop: false,
+				#[cfg(feature = "futures-core")]
+				waker: None,
+			}),
+			capacity,
+			policy,
+			frame_available: Condvar::new(),
+			space_available: Condvar::new(),
+		});
+		let reader = thread::Builder::new()
+			.name("opencv-video-capture".to_string())
+			.spawn({
+				let shared = Arc::clone(&shared);
+				move || {
+					let mut guard = FinishGuard { shared, error: None };
+					for frame in capture.frames() {
+						match frame {
+							Ok(frame) => {
+								if !guard.shared.push(frame) {
+									break;
+								}
+							}
+							Err(e) => {
+								guard.error = Some(e);
+							}
+						}
+					}
+					drop(guard);
+					capture
+				}
+			})
+			.map_err(|e| Error::new(core::StsError, format!("Can't spawn the reader thread: {}", e)))?;
+		Ok(Self { shared, reader: Some(reader) })
+	}
+
+	/// Blocks until a frame is available and returns the most recent one discarding the older buffered frames
+	///
+	/// Returns `None` when the capture has ended and all frames have been consumed.
+	pub fn latest(&self) -> Option<Result<Frame>> {
+		let state = self.shared.wait_frames();
+		self.shared.take_frame(state, |frames| {
+			let out = frames.pop_back();
+			frames.clear();
+			out
+		})
+	}
+
+	/// Blocks until a frame is available and returns the oldest buffered one
+	///
+	/// Returns `None` when the capture has ended and all frames have been consumed.
+	pub fn next_frame(&self) -> Option<Result<Frame>> {
+		let state = self.shared.wait_frames();
+		self.shared.take_frame(state, VecDeque::pop_front)
+	}
+
+	/// Returns the oldest buffered frame without blocking
+	pub fn try_next_frame(&self) -> Option<Result<Frame>> {
+		let state = self.shared.state();
+		self.shared.take_frame(state, VecDeque::pop_front)
+	}
+
+	/// Number of frames discarded because of `OverflowPolicy::DropOldest` so far
+	pub fn dropped_frames(&self) -> u64 {
+		self.shared.state().dropped
+	}
+
+	/// Stops the reader thread and returns the underlying `VideoCapture`, buffered frames are discarded
+	///
+	/// Like dropping, this blocks until the `read()` that is in progress returns.
+	pub fn into_inner(mut self) -> Result<VideoCapture> {
+		self.shared.stop();
+		self.reader.take()
+			.expect("Reader thread is only taken on drop")
+			.join()
+			.map_err(|_| Error::new(core::StsError, "Reader thread panicked".to_string()))
+	}
+}
+
+impl Drop for AsyncVideoCapture {
+	fn drop(&mut self) {
+		if let Some(reader) = self.reader.take() {
+			self.shared.stop();
+			let _ = reader.join();
+		}
+	}
+}
+
+/// Stream of the buffered frames in the order they were read
+#[cfg(feature = "futures-core")]
+impl futures_core::Stream for AsyncVideoCapture {
+	type Item = Result<Frame>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let mut state = self.shared.state();
+		if state.frames.is_empty() && !state.finished {
+			state.waker = Some(cx.waker().clone());
+			Poll::Pending
+		} else {
+			Poll::Ready(self.shared.take_frame(state, VecDeque::pop_front))
+		}
+	}
+}
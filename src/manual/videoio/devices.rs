@@ -0,0 +1,187 @@
+use crate::{
+	core::{self, Size},
+	Error,
+	prelude::*,
+	Result,
+	videoio::{self, FourCC, VideoCapture, VideoCaptureAPIs},
+};
+
+/// Number of device indices that are probed for every camera backend by `list_devices()`
+pub const MAX_PROBED_DEVICES: i32 = 8;
+
+const PROBED_FOURCCS: [[u8; 4]; 3] = [*b"MJPG", *b"YUYV", *b"H264"];
+
+const PROBED_FRAME_SIZES: [(i32, i32); 8] = [
+	(320, 240),
+	(640, 480),
+	(800, 600),
+	(1024, 768),
+	(1280, 720),
+	(1920, 1080),
+	(2560, 1440),
+	(3840, 2160),
+];
+
+/// Combination of the pixel format and frame size that the device accepted
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CaptureMode {
+	pub fourcc: FourCC,
+	pub frame_size: Size,
+}
+
+/// Camera found by `list_devices()`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeviceInfo {
+	/// Index to pass to `VideoCapture::new()` together with `backend`
+	pub index: i32,
+	pub backend: VideoCaptureAPIs,
+	/// OpenCV doesn't expose the device names so this is built from the backend name and the index, e.g. `V4L2 #0`
+	pub name: String,
+	/// Modes that were accepted during probing, the first one is the default mode of the device
+	pub modes: Vec<CaptureMode>,
+}
+
+/// Requirements for `VideoCapture::open_best()`, unset fields are not checked
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CaptureRequirements {
+	/// Only consider the devices of this backend
+	pub backend: Option<VideoCaptureAPIs>,
+	pub fourcc: Option<FourCC>,
+	/// Smallest acceptable frame size
+	pub min_frame_size: Option<Size>,
+	/// Frame size to prefer among the acceptable ones, the largest one is preferred by default
+	pub preferred_frame_size: Option<Size>,
+	/// Smallest acceptable frame rate, it's checked after the device is opened in the selected mode
+	pub min_fps: Option<f64>,
+}
+
+impl CaptureRequirements {
+	/// Checks whether `mode` satisfies the `fourcc` and `min_frame_size` requirements
+	pub fn accepts(&self, mode: &CaptureMode) -> bool {
+		self.fourcc.map_or(true, |fourcc| fourcc == mode.fourcc)
+			&& self.min_frame_size.map_or(true, |min| mode.frame_size.width >= min.width && mode.frame_size.height >= min.height)
+	}
+
+	/// Rank of the accepted `mode` according to `preferred_frame_size`, lower is better
+	pub fn score(&self, mode: &CaptureMode) -> i64 {
+		let area = i64::from(mode.frame_size.width) * i64::from(mode.frame_size.height);
+		match self.preferred_frame_size {
+			Some(preferred) => (area - i64::from(preferred.width) * i64::from(preferred.height)).abs(),
+			None => -area,
+		}
+	}
+}
+
+/// Sets the fourcc and frame size and checks whether the device actually switched to them
+fn apply_mode(capture: &mut VideoCapture, mode: &CaptureMode) -> Result<bool> {
+	// most backends report unsupported values by silently keeping the old ones so the result of set() is not enough
+	let _ = capture.set_fourcc(mode.fourcc);
+	let _ = capture.set_frame_size(mode.frame_size);
+	Ok(capture.fourcc()? == mode.fourcc && capture.frame_size()? == mode.frame_size)
+}
+
+/// Switches the opened device to `mode` and checks that it can provide at least `min_fps`
+fn select_mode(capture: &mut VideoCapture, mode: &CaptureMode, min_fps: Option<f64>) -> Result<bool> {
+	if !apply_mode(capture, mode)? {
+		return Ok(false);
+	}
+	if let Some(min_fps) = min_fps {
+		if capture.fps()? < min_fps {
+			let _ = capture.set_fps(min_fps);
+			return Ok(capture.fps()? >= min_fps);
+		}
+	}
+	Ok(true)
+}
+
+fn probe_modes(capture: &mut VideoCapture) -> Result<Vec<CaptureMode>> {
+	let default_mode = CaptureMode { fourcc: capture.fourcc()?, frame_size: capture.frame_size()? };
+	let mut out = vec![default_mode];
+	let mut fourccs = PROBED_FOURCCS.iter()
+		.map(|&chars| FourCC::new(chars))
+		.collect::<Vec<_>>();
+	if !fourccs.contains(&default_mode.fourcc) {
+		fourccs.push(default_mode.fourcc);
+	}
+	for fourcc in fourccs {
+		for &(width, height) in &PROBED_FRAME_SIZES {
+			let mode = CaptureMode { fourcc, frame_size: Size::new(width, height) };
+			if !out.contains(&mode) && apply_mode(capture, &mode)? {
+				out.push(mode);
+			}
+		}
+	}
+	Ok(out)
+}
+
+fn open_device(index: i32, backend: VideoCaptureAPIs) -> Option<VideoCapture> {
+	// a missing device is reported either as an error or as a capture that is not opened depending on the backend
+	VideoCapture::new(index, backend as i32).ok()
+		.filter(|capture| capture.is_opened().unwrap_or(false))
+}
+
+/// Devices of the `backend` that could be opened, the devices that fail during probing are skipped
+fn list_backend_devices(backend: VideoCaptureAPIs, probe: bool) -> Vec<DeviceInfo> {
+	let backend_name = videoio::get_backend_name(backend).unwrap_or_else(|_| format!("{:?}", backend));
+	let mut out = vec![];
+	for index in 0..MAX_PROBED_DEVICES {
+		if let Some(mut capture) = open_device(index, backend) {
+			let modes = if probe {
+				match probe_modes(&mut capture) {
+					Ok(modes) => modes,
+					// e.g. a device that was unplugged or is busy, it shouldn't hide the other cameras
+					Err(_) => continue,
+				}
+			} else {
+				vec![]
+			};
+			out.push(DeviceInfo { index, backend, name: format!("{} #{}", backend_name, index), modes });
+		}
+	}
+	out
+}
+
+/// Finds the cameras by trying the indices up to `MAX_PROBED_DEVICES` with every backend from
+/// `get_camera_backends()` and probes the supported modes of each one
+///
+/// The same physical camera is usually reported once for every backend that can access it. Probing sets the pixel
+/// format and frame size to the common values and reads them back, so it can take a few seconds per camera. Cameras that
+/// fail while being probed are left out of the result instead of failing the whole enumeration.
+pub fn list_devices() -> Result<Vec<DeviceInfo>> {
+	let mut out = vec![];
+	for backend in videoio::get_camera_backends()?.iter() {
+		out.extend(list_backend_devices(backend, true));
+	}
+	Ok(out)
+}
+
+impl VideoCapture {
+	/// Opens the camera and the mode that satisfy `requirements` best, see `list_devices()` for how they are found
+	///
+	/// The returned capture is already switched to the selected mode.
+	pub fn open_best(requirements: &CaptureRequirements) -> Result<VideoCapture> {
+		let backends = match requirements.backend {
+			Some(backend) => vec![backend],
+			None => videoio::get_camera_backends()?.to_vec(),
+		};
+		let mut candidates = vec![];
+		for backend in backends {
+			for device in list_backend_devices(backend, true) {
+				for mode in device.modes.iter().filter(|mode| requirements.accepts(mode)) {
+					candidates.push((requirements.score(mode), device.index, device.backend, *mode));
+				}
+			}
+		}
+		// stable sort keeps the order of the backends and devices for the equal scores
+		candidates.sort_by_key(|&(score, ..)| score);
+		for (_, index, backend, mode) in candidates {
+			if let Some(mut capture) = open_device(index, backend) {
+				// a failing device is skipped like in list_devices(), the next candidate may still work
+				if select_mode(&mut capture, &mode, requirements.min_fps).unwrap_or(false) {
+					return Ok(capture);
+				}
+			}
+		}
+		Err(Error::new(core::StsError, format!("No camera satisfies the requirements: {:?}", requirements)))
+	}
+}
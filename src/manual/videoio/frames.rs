@@ -0,0 +1,93 @@
+use std::{
+	iter::FusedIterator,
+	time::Duration,
+};
+
+use crate::{
+	core::Mat,
+	prelude::*,
+	Result,
+	videoio::VideoCapture,
+};
+
+/// Single frame read from the `VideoCapture`
+#[derive(Debug)]
+pub struct Frame {
+	pub mat: Mat,
+	/// Position of the frame in the video as reported by `CAP_PROP_POS_MSEC`
+	pub timestamp: Duration,
+	/// Sequential number of the frame counting from the start of the iteration
+	pub index: u64,
+}
+
+/// Iterator over the frames of the `VideoCapture`, see `VideoCapture::frames()` and `VideoCapture::frames_into()`
+///
+/// The iteration ends when the capture can't provide the next frame (end of file, disconnected camera) or after the
+/// first error.
+pub struct Frames<'c, 'b> {
+	capture: &'c mut VideoCapture,
+	buffer: Option<&'b mut Mat>,
+	index: u64,
+	finished: bool,
+}
+
+impl<'c, 'b> Frames<'c, 'b> {
+	fn new(capture: &'c mut VideoCapture, buffer: Option<&'b mut Mat>) -> Self {
+		Self { capture, buffer, index: 0, finished: false }
+	}
+
+	fn read_frame(&mut self) -> Result<Option<Frame>> {
+		let mat = if let Some(buffer) = self.buffer.as_deref_mut() {
+			if !read_into(self.capture, buffer)? {
+				return Ok(None);
+			}
+			Mat::copy(buffer)?
+		} else {
+			let mut mat = Mat::default();
+			if !read_into(self.capture, &mut mat)? {
+				return Ok(None);
+			}
+			mat
+		};
+		let timestamp = self.capture.position()?;
+		let index = self.index;
+		self.index += 1;
+		Ok(Some(Frame { mat, timestamp, index }))
+	}
+}
+
+impl Iterator for Frames<'_, '_> {
+	type Item = Result<Frame>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished {
+			return None;
+		}
+		let out = self.read_frame().transpose();
+		if !matches!(out, Some(Ok(_))) {
+			self.finished = true;
+		}
+		out
+	}
+}
+
+impl FusedIterator for Frames<'_, '_> {}
+
+fn read_into(capture: &mut VideoCapture, mat: &mut Mat) -> Result<bool> {
+	Ok(capture.read(mat)? && !mat.empty()?)
+}
+
+impl VideoCapture {
+	/// Returns an iterator that reads the frames from the capture, every frame is allocated anew
+	pub fn frames(&mut self) -> Frames<'_, 'static> {
+		Frames::new(self, None)
+	}
+
+	/// Returns an iterator that reads the frames from the capture into the provided `buffer`
+	///
+	/// `Frame::mat` shares the data with `buffer`, so it's overwritten by the next read. Use `try_clone()` to keep
+	/// the frame around for longer than one iteration.
+	pub fn frames_into<'b>(&mut self, buffer: &'b mut Mat) -> Frames<'_, 'b> {
+		Frames::new(self, Some(buffer))
+	}
+}
@@ -0,0 +1,190 @@
+use std::{
+	fmt,
+	str::FromStr,
+	time::Duration,
+};
+
+use crate::{
+	core::{self, Size},
+	Error,
+	prelude::*,
+	Result,
+	videoio::{
+		CAP_PROP_FOURCC,
+		CAP_PROP_FPS,
+		CAP_PROP_FRAME_HEIGHT,
+		CAP_PROP_FRAME_WIDTH,
+		CAP_PROP_POS_MSEC,
+		VIDEOWRITER_PROP_QUALITY,
+		VideoCaptureTrait,
+		VideoWriterTrait,
+	},
+};
+#[cfg(not(ocvrs_opencv_branch_32))]
+use crate::videoio::{self, CAP_PROP_BACKEND, VideoCaptureAPIs};
+
+/// Four character code of the video codec or pixel format, e.g. `MJPG` or `YUYV`
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FourCC([u8; 4]);
+
+impl FourCC {
+	#[inline]
+	pub const fn new(chars: [u8; 4]) -> Self {
+		Self(chars)
+	}
+
+	/// Creates `FourCC` from the numeric code as returned from `CAP_PROP_FOURCC` or `VideoWriter::fourcc()`
+	#[inline]
+	pub const fn from_code(code: i32) -> Self {
+		Self((code as u32).to_le_bytes())
+	}
+
+	/// Numeric code suitable for `CAP_PROP_FOURCC` or `VideoWriter::new()`
+	#[inline]
+	pub const fn code(self) -> i32 {
+		u32::from_le_bytes(self.0) as i32
+	}
+
+	#[inline]
+	pub fn chars(self) -> [u8; 4] {
+		self.0
+	}
+}
+
+impl FromStr for FourCC {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		match s.as_bytes() {
+			&[c1, c2, c3, c4] if s.is_ascii() => Ok(Self([c1, c2, c3, c4])),
+			_ => Err(Error::new(core::StsBadArg, format!("FourCC must consist of 4 ASCII characters, but got: {:?}", s))),
+		}
+	}
+}
+
+impl fmt::Display for FourCC {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.iter()
+			.map(|&c| if c.is_ascii_graphic() || c == b' ' { char::from(c) } else { '?' })
+			.try_for_each(|c| fmt::Write::write_char(f, c))
+	}
+}
+
+impl fmt::Debug for FourCC {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "FourCC(\"{}\")", self)
+	}
+}
+
+/// Sets the property and turns the `false` returned by OpenCV for the unsupported properties into an error
+#[inline]
+fn checked_set(set: impl FnOnce(i32, f64) -> Result<bool>, prop_id: i32, value: f64) -> Result<()> {
+	if set(prop_id, value)? {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsBadArg, format!("Property: {} can't be set to: {}, it's not supported by the backend", prop_id, value)))
+	}
+}
+
+#[cfg(not(ocvrs_opencv_branch_32))]
+/// Negative values are clamped to zero, the values that don't fit into `Duration` are reported as errors
+fn duration_from_msec(msec: f64) -> Result<Duration> {
+	let secs = msec.max(0.) / 1000.;
+	// u64::MAX as f64 rounds up to 2^64 which is already out of the Duration range
+	if secs.is_finite() && secs < u64::MAX as f64 {
+		Ok(Duration::from_secs_f64(secs))
+	} else {
+		Err(Error::new(core::StsOutOfRange, format!("Position: {} ms reported by the backend is out of range", msec)))
+	}
+}
+
+fn backend_from_code(code: f64) -> Result<VideoCaptureAPIs> {
+	let code = code as i32;
+	videoio::get_backends()?.iter()
+		.find(|&api| api as i32 == code)
+		.ok_or_else(|| Error::new(core::StsBadArg, format!("Unknown backend: {}", code)))
+}
+
+/// Typed accessors for the common `VideoCapture` properties
+///
+/// Setters return an error if the backend doesn't support the property instead of silently returning `false`.
+pub trait VideoCaptureTraitManual: VideoCaptureTrait {
+	/// Frame rate, `CAP_PROP_FPS`
+	#[inline]
+	fn fps(&self) -> Result<f64> {
+		self.get(CAP_PROP_FPS)
+	}
+
+	#[inline]
+	fn set_fps(&mut self, fps: f64) -> Result<()> {
+		checked_set(|prop_id, value| self.set(prop_id, value), CAP_PROP_FPS, fps)
+	}
+
+	/// Size of the frames, `CAP_PROP_FRAME_WIDTH` and `CAP_PROP_FRAME_HEIGHT`
+	#[inline]
+	fn frame_size(&self) -> Result<Size> {
+		Ok(Size::new(self.get(CAP_PROP_FRAME_WIDTH)? as i32, self.get(CAP_PROP_FRAME_HEIGHT)? as i32))
+	}
+
+	#[inline]
+	fn set_frame_size(&mut self, size: Size) -> Result<()> {
+		checked_set(|prop_id, value| self.set(prop_id, value), CAP_PROP_FRAME_WIDTH, f64::from(size.width))?;
+		checked_set(|prop_id, value| self.set(prop_id, value), CAP_PROP_FRAME_HEIGHT, f64::from(size.height))
+	}
+
+	/// Codec or pixel format of the stream, `CAP_PROP_FOURCC`
+	#[inline]
+	fn fourcc(&self) -> Result<FourCC> {
+		self.get(CAP_PROP_FOURCC).map(|code| FourCC::from_code(code as i32))
+	}
+
+	#[inline]
+	fn set_fourcc(&mut self, fourcc: FourCC) -> Result<()> {
+		checked_set(|prop_id, value| self.set(prop_id, value), CAP_PROP_FOURCC, f64::from(fourcc.code()))
+	}
+
+	/// Current position in the video, `CAP_PROP_POS_MSEC`
+	#[inline]
+	fn position(&self) -> Result<Duration> {
+		self.get(CAP_PROP_POS_MSEC).and_then(duration_from_msec)
+	}
+
+	#[inline]
+	fn set_position(&mut self, position: Duration) -> Result<()> {
+		checked_set(|prop_id, value| self.set(prop_id, value), CAP_PROP_POS_MSEC, position.as_secs_f64() * 1000.)
+	}
+
+	/// Backend that is used for the capture, `CAP_PROP_BACKEND`
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	#[inline]
+	fn backend(&self) -> Result<VideoCaptureAPIs> {
+		backend_from_code(self.get(CAP_PROP_BACKEND)?)
+	}
+}
+
+impl<T: VideoCaptureTrait + ?Sized> VideoCaptureTraitManual for T {}
+
+/// Typed accessors for the common `VideoWriter` properties
+///
+/// Setters return an error if the backend doesn't support the property instead of silently returning `false`.
+pub trait VideoWriterTraitManual: VideoWriterTrait {
+	/// Encoding quality in percent, `VIDEOWRITER_PROP_QUALITY`
+	#[inline]
+	fn quality(&self) -> Result<f64> {
+		self.get(VIDEOWRITER_PROP_QUALITY)
+	}
+
+	#[inline]
+	fn set_quality(&mut self, quality: f64) -> Result<()> {
+		checked_set(|prop_id, value| self.set(prop_id, value), VIDEOWRITER_PROP_QUALITY, quality)
+	}
+
+	/// Backend that is used for the writer, `CAP_PROP_BACKEND`
+	#[cfg(not(ocvrs_opencv_branch_32))]
+	#[inline]
+	fn backend(&self) -> Result<VideoCaptureAPIs> {
+		backend_from_code(self.get(CAP_PROP_BACKEND)?)
+	}
+}
+
+impl<T: VideoWriterTrait + ?Sized> VideoWriterTraitManual for T {}
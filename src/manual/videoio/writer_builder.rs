@@ -0,0 +1,171 @@
+use std::{
+	ffi::{c_void, CString},
+	fs,
+	os::raw::c_char,
+	path::PathBuf,
+};
+
+use crate::{
+	core::{self, Size, Vector},
+	Error,
+	prelude::*,
+	Result,
+	sys,
+	videoio::{self, FourCC, VideoCaptureAPIs, VideoWriter},
+};
+
+/// Builder for `VideoWriter` that checks the settings before opening and reports the backends that were tried
+///
+/// Use `VideoWriter::builder()` to create it.
+#[derive(Clone, Debug)]
+pub struct VideoWriterBuilder {
+	path: PathBuf,
+	fourcc: Option<FourCC>,
+	fps: Option<f64>,
+	frame_size: Option<Size>,
+	is_color: bool,
+	backend: Option<VideoCaptureAPIs>,
+	params: Vec<(i32, i32)>,
+}
+
+impl VideoWriterBuilder {
+	/// Codec of the output video, required
+	pub fn fourcc(mut self, fourcc: FourCC) -> Self {
+		self.fourcc = Some(fourcc);
+		self
+	}
+
+	/// Frame rate of the output video, required
+	pub fn fps(mut self, fps: f64) -> Self {
+		self.fps = Some(fps);
+		self
+	}
+
+	/// Size of the frames that will be written, required
+	pub fn frame_size(mut self, frame_size: Size) -> Self {
+		self.frame_size = Some(frame_size);
+		self
+	}
+
+	/// Whether the frames are color or grayscale, default is `true`
+	pub fn is_color(mut self, is_color: bool) -> Self {
+		self.is_color = is_color;
+		self
+	}
+
+	/// Backend to use, by default all backends from `videoio::get_writer_backends()` are tried in order
+	pub fn backend(mut self, backend: VideoCaptureAPIs) -> Self {
+		self.backend = Some(backend);
+		self
+	}
+
+	/// Additional `VIDEOWRITER_PROP_*` parameters as `(property id, value)` pairs, require OpenCV 4.5.2+
+	pub fn params(mut self, params: impl IntoIterator<Item=(i32, i32)>) -> Self {
+		self.params.extend(params);
+		self
+	}
+
+	/// Validates the settings and opens the `VideoWriter`
+	///
+	/// Returns an error listing the backends that were tried if none of them could open the output.
+	pub fn build(self) -> Result<VideoWriter> {
+		let path = self.path.to_str()
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Output path is not valid UTF-8: {}", self.path.display())))?;
+		if path.is_empty() {
+			return Err(Error::new(core::StsBadArg, "Output path is empty".to_string()));
+		}
+		if let Some(parent) = self.path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+			if !parent.is_dir() {
+				return Err(Error::new(core::StsObjectNotFound, format!("Output directory doesn't exist: {}", parent.display())));
+			}
+		}
+		let fourcc = self.fourcc
+			.ok_or_else(|| Error::new(core::StsBadArg, "FourCC is not set".to_string()))?;
+		let fps = self.fps
+			.ok_or_else(|| Error::new(core::StsBadArg, "Frame rate is not set".to_string()))?;
+		if !fps.is_finite() || fps <= 0. {
+			return Err(Error::new(core::StsBadArg, format!("Frame rate must be positive, but is: {}", fps)));
+		}
+		let frame_size = self.frame_size
+			.ok_or_else(|| Error::new(core::StsBadArg, "Frame size is not set".to_string()))?;
+		if frame_size.width <= 0 || frame_size.height <= 0 {
+			return Err(Error::new(core::StsBadSize, format!("Frame size must be positive, but is: {}x{}", frame_size.width, frame_size.height)));
+		}
+
+		let available = videoio::get_writer_backends()?;
+		let backends = if let Some(backend) = self.backend {
+			if !available.iter().any(|api| api == backend) {
+				let available = available.iter()
+					.map(videoio::get_backend_name)
+					.collect::<Result<Vec<_>>>()?;
+				return Err(Error::new(core::StsBadArg, format!(
+					"Backend: {} is not available for writing, available backends are: {}",
+					videoio::get_backend_name(backend)?,
+					available.join(", "),
+				)));
+			}
+			vec![backend]
+		} else {
+			available.to_vec()
+		};
+
+		let path_existed = self.path.exists();
+		let filename = CString::new(path)?;
+		let params = self.params.iter()
+			.flat_map(|&(prop_id, value)| vec![prop_id, value])
+			.collect::<Vector<i32>>();
+		let mut writer = VideoWriter::default()?;
+		let mut failures = Vec::with_capacity(backends.len());
+		for backend in backends {
+			let opened = unsafe {
+				cv_manual_VideoWriter_open(
+					writer.as_raw_mut_VideoWriter(),
+					filename.as_ptr(),
+					backend as i32,
+					fourcc.code(),
+					fps,
+					&frame_size,
+					self.is_color,
+					params.as_raw_VectorOfi32(),
+				)
+			}.into_result();
+			match opened {
+				Ok(true) => return Ok(writer),
+				Ok(false) => failures.push(format!("{} (failed to open)", videoio::get_backend_name(backend)?)),
+				Err(e) => failures.push(format!("{} ({})", videoio::get_backend_name(backend)?, e.message)),
+			}
+		}
+		// backends can leave an empty file behind after a failed attempt
+		if !path_existed && fs::metadata(&self.path).map_or(false, |meta| meta.len() == 0) {
+			let _ = fs::remove_file(&self.path);
+		}
+		Err(Error::new(core::StsError, format!(
+			"Can't open VideoWriter for: {} with FourCC: {}, fps: {}, frame size: {}x{}, tried backends: {}",
+			path,
+			fourcc,
+			fps,
+			frame_size.width,
+			frame_size.height,
+			if failures.is_empty() { "none".to_string() } else { failures.join(", ") },
+		)))
+	}
+}
+
+impl VideoWriter {
+	/// Starts building a `VideoWriter` that will write to `path`
+	pub fn builder(path: impl Into<PathBuf>) -> VideoWriterBuilder {
+		VideoWriterBuilder {
+			path: path.into(),
+			fourcc: None,
+			fps: None,
+			frame_size: None,
+			is_color: true,
+			backend: None,
+			params: vec![],
+		}
+	}
+}
+
+extern "C" {
+	fn cv_manual_VideoWriter_open(instance: *mut c_void, filename: *const c_char, api_preference: i32, fourcc: i32, fps: f64, frame_size: *const Size, is_color: bool, params: *const c_void) -> sys::Result<bool>;
+}
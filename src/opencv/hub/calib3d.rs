@@ -4990,3 +4990,4 @@ impl UsacParams {
 	}
 	
 }
+pub use crate::manual::calib3d::*;
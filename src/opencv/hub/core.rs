@@ -10841,8 +10841,6 @@ impl MatConstIterator {
 	#[inline] pub fn as_raw_mut_MatConstIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
 }
 
-unsafe impl Send for MatConstIterator {}
-
 impl core::MatConstIteratorTrait for MatConstIterator {
 	#[inline] fn as_raw_MatConstIterator(&self) -> *const c_void { self.as_raw() }
 	#[inline] fn as_raw_mut_MatConstIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
@@ -12744,6 +12742,7 @@ impl Range {
 }
 
 unsafe impl Send for Range {}
+unsafe impl Sync for Range {}
 
 impl core::RangeTrait for Range {
 	#[inline] fn as_raw_Range(&self) -> *const c_void { self.as_raw() }
@@ -13766,8 +13765,6 @@ impl SparseMat_Node {
 	#[inline] pub fn as_raw_mut_SparseMat_Node(&mut self) -> *mut c_void { self.as_raw_mut() }
 }
 
-unsafe impl Send for SparseMat_Node {}
-
 impl core::SparseMat_NodeTrait for SparseMat_Node {
 	#[inline] fn as_raw_SparseMat_Node(&self) -> *const c_void { self.as_raw() }
 	#[inline] fn as_raw_mut_SparseMat_Node(&mut self) -> *mut c_void { self.as_raw_mut() }
@@ -13852,8 +13849,6 @@ impl SparseMatConstIterator {
 	#[inline] pub fn as_raw_mut_SparseMatConstIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
 }
 
-unsafe impl Send for SparseMatConstIterator {}
-
 impl core::SparseMatConstIteratorTrait for SparseMatConstIterator {
 	#[inline] fn as_raw_SparseMatConstIterator(&self) -> *const c_void { self.as_raw() }
 	#[inline] fn as_raw_mut_SparseMatConstIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
@@ -13914,8 +13909,6 @@ impl SparseMatIterator {
 	#[inline] pub fn as_raw_mut_SparseMatIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
 }
 
-unsafe impl Send for SparseMatIterator {}
-
 impl core::SparseMatConstIteratorTrait for SparseMatIterator {
 	#[inline] fn as_raw_SparseMatConstIterator(&self) -> *const c_void { self.as_raw() }
 	#[inline] fn as_raw_mut_SparseMatConstIterator(&mut self) -> *mut c_void { self.as_raw_mut() }
@@ -14759,8 +14752,6 @@ impl UMatData {
 	#[inline] pub fn as_raw_mut_UMatData(&mut self) -> *mut c_void { self.as_raw_mut() }
 }
 
-unsafe impl Send for UMatData {}
-
 impl core::UMatDataTrait for UMatData {
 	#[inline] fn as_raw_UMatData(&self) -> *const c_void { self.as_raw() }
 	#[inline] fn as_raw_mut_UMatData(&mut self) -> *mut c_void { self.as_raw_mut() }
@@ -19421,8 +19412,6 @@ impl Queue {
 	#[inline] pub fn as_raw_mut_Queue(&mut self) -> *mut c_void { self.as_raw_mut() }
 }
 
-unsafe impl Send for Queue {}
-
 impl core::QueueTrait for Queue {
 	#[inline] fn as_raw_Queue(&self) -> *const c_void { self.as_raw() }
 	#[inline] fn as_raw_mut_Queue(&mut self) -> *mut c_void { self.as_raw_mut() }
@@ -511,6 +511,7 @@ pub fn create_trackbar(trackbarname: &str, winname: &str, value: &mut i32, count
 /// 
 /// The function destroyAllWindows destroys all of the opened HighGUI windows.
 pub fn destroy_all_windows() -> Result<()> {
+	if let Some(out) = crate::manual::highgui::intercept::destroy_all_windows() { return out; }
 	unsafe { sys::cv_destroyAllWindows() }.into_result()
 }
 
@@ -521,6 +522,7 @@ pub fn destroy_all_windows() -> Result<()> {
 /// ## Parameters
 /// * winname: Name of the window to be destroyed.
 pub fn destroy_window(winname: &str) -> Result<()> {
+	if let Some(out) = crate::manual::highgui::intercept::destroy_window(winname) { return out; }
 	extern_container_arg!(winname);
 	unsafe { sys::cv_destroyWindow_const_StringR(winname.opencv_as_extern()) }.into_result()
 }
@@ -707,6 +709,7 @@ pub fn get_window_property(winname: &str, prop_id: i32) -> Result<f64> {
 /// * winname: Name of the window.
 /// * mat: Image to be shown.
 pub fn imshow(winname: &str, mat: &dyn core::ToInputArray) -> Result<()> {
+	if let Some(out) = crate::manual::highgui::intercept::imshow(winname, mat) { return out; }
 	extern_container_arg!(winname);
 	input_array_arg!(mat);
 	unsafe { sys::cv_imshow_const_StringR_const__InputArrayR(winname.opencv_as_extern(), mat.as_raw__InputArray()) }.into_result()
@@ -766,6 +769,7 @@ pub fn move_window(winname: &str, x: i32, y: i32) -> Result<()> {
 /// ## C++ default parameters
 /// * flags: WINDOW_AUTOSIZE
 pub fn named_window(winname: &str, flags: i32) -> Result<()> {
+	if let Some(out) = crate::manual::highgui::intercept::named_window(winname, flags) { return out; }
 	extern_container_arg!(winname);
 	unsafe { sys::cv_namedWindow_const_StringR_int(winname.opencv_as_extern(), flags) }.into_result()
 }
@@ -1114,6 +1118,7 @@ pub fn update_window(winname: &str) -> Result<()> {
 /// ## C++ default parameters
 /// * delay: 0
 pub fn wait_key_ex(delay: i32) -> Result<i32> {
+	if let Some(out) = crate::manual::highgui::intercept::wait_key_ex(delay) { return out; }
 	unsafe { sys::cv_waitKeyEx_int(delay) }.into_result()
 }
 
@@ -1141,6 +1146,7 @@ pub fn wait_key_ex(delay: i32) -> Result<i32> {
 /// ## C++ default parameters
 /// * delay: 0
 pub fn wait_key(delay: i32) -> Result<i32> {
+	if let Some(out) = crate::manual::highgui::intercept::wait_key(delay) { return out; }
 	unsafe { sys::cv_waitKey_int(delay) }.into_result()
 }
 
@@ -1273,3 +1279,4 @@ impl crate::highgui::QtFontTrait for QtFont {
 
 impl QtFont {
 }
+pub use crate::manual::highgui::*;
@@ -616,6 +616,9 @@ mod core_types {
 		#[inline] pub fn as_raw_mut_PtrOff32(&mut self) -> *mut c_void { self.as_raw_mut() }
 	}
 	
+	unsafe impl Send for core::Ptr::<f32> {}
+	unsafe impl Sync for core::Ptr::<f32> {}
+	
 	pub type VectorOfDMatch = core::Vector::<core::DMatch>;
 	
 	impl VectorOfDMatch {
@@ -638,6 +641,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::DMatch> {}
+	unsafe impl Sync for core::Vector::<core::DMatch> {}
 	
 	pub type VectorOfGpuMat = core::Vector::<core::GpuMat>;
 	
@@ -681,6 +685,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::KeyPoint> {}
+	unsafe impl Sync for core::Vector::<core::KeyPoint> {}
 	
 	pub type VectorOfMat = core::Vector::<core::Mat>;
 	
@@ -744,6 +749,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point> {}
+	unsafe impl Sync for core::Vector::<core::Point> {}
 	
 	impl core::ToInputArray for VectorOfPoint {
 		#[inline]
@@ -818,6 +824,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point2d> {}
+	unsafe impl Sync for core::Vector::<core::Point2d> {}
 	
 	impl core::ToInputArray for VectorOfPoint2d {
 		#[inline]
@@ -892,6 +899,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point2f> {}
+	unsafe impl Sync for core::Vector::<core::Point2f> {}
 	
 	impl core::ToInputArray for VectorOfPoint2f {
 		#[inline]
@@ -966,6 +974,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point3d> {}
+	unsafe impl Sync for core::Vector::<core::Point3d> {}
 	
 	impl core::ToInputArray for VectorOfPoint3d {
 		#[inline]
@@ -1040,6 +1049,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point3f> {}
+	unsafe impl Sync for core::Vector::<core::Point3f> {}
 	
 	impl core::ToInputArray for VectorOfPoint3f {
 		#[inline]
@@ -1114,6 +1124,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point3i> {}
+	unsafe impl Sync for core::Vector::<core::Point3i> {}
 	
 	impl core::ToInputArray for VectorOfPoint3i {
 		#[inline]
@@ -1185,6 +1196,7 @@ mod core_types {
 	vector_non_copy_or_bool! { core::Range }
 	
 	unsafe impl Send for core::Vector::<core::Range> {}
+	unsafe impl Sync for core::Vector::<core::Range> {}
 	
 	pub type VectorOfRect = core::Vector::<core::Rect>;
 	
@@ -1208,6 +1220,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Rect> {}
+	unsafe impl Sync for core::Vector::<core::Rect> {}
 	
 	impl core::ToInputArray for VectorOfRect {
 		#[inline]
@@ -1282,6 +1295,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Rect2d> {}
+	unsafe impl Sync for core::Vector::<core::Rect2d> {}
 	
 	impl core::ToInputArray for VectorOfRect2d {
 		#[inline]
@@ -1376,6 +1390,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Scalar> {}
+	unsafe impl Sync for core::Vector::<core::Scalar> {}
 	
 	impl core::ToInputArray for VectorOfScalar {
 		#[inline]
@@ -1450,6 +1465,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Size> {}
+	unsafe impl Sync for core::Vector::<core::Size> {}
 	
 	impl core::ToInputArray for VectorOfSize {
 		#[inline]
@@ -1521,6 +1537,7 @@ mod core_types {
 	vector_non_copy_or_bool! { String }
 	
 	unsafe impl Send for core::Vector::<String> {}
+	unsafe impl Sync for core::Vector::<String> {}
 	
 	pub type VectorOfUMat = core::Vector::<core::UMat>;
 	
@@ -1564,6 +1581,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec2i> {}
+	unsafe impl Sync for core::Vector::<core::Vec2i> {}
 	
 	impl core::ToInputArray for VectorOfVec2i {
 		#[inline]
@@ -1638,6 +1656,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec3d> {}
+	unsafe impl Sync for core::Vector::<core::Vec3d> {}
 	
 	impl core::ToInputArray for VectorOfVec3d {
 		#[inline]
@@ -1712,6 +1731,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec3f> {}
+	unsafe impl Sync for core::Vector::<core::Vec3f> {}
 	
 	impl core::ToInputArray for VectorOfVec3f {
 		#[inline]
@@ -1786,6 +1806,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec3i> {}
+	unsafe impl Sync for core::Vector::<core::Vec3i> {}
 	
 	impl core::ToInputArray for VectorOfVec3i {
 		#[inline]
@@ -1860,6 +1881,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec4f> {}
+	unsafe impl Sync for core::Vector::<core::Vec4f> {}
 	
 	impl core::ToInputArray for VectorOfVec4f {
 		#[inline]
@@ -1934,6 +1956,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec4i> {}
+	unsafe impl Sync for core::Vector::<core::Vec4i> {}
 	
 	impl core::ToInputArray for VectorOfVec4i {
 		#[inline]
@@ -2008,6 +2031,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec6f> {}
+	unsafe impl Sync for core::Vector::<core::Vec6f> {}
 	
 	impl core::ToInputArray for VectorOfVec6f {
 		#[inline]
@@ -2079,6 +2103,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<core::DMatch> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<core::DMatch>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<core::DMatch>> {}
 	
 	pub type VectorOfVectorOfKeyPoint = core::Vector::<core::Vector::<core::KeyPoint>>;
 	
@@ -2099,6 +2124,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<core::KeyPoint> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<core::KeyPoint>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<core::KeyPoint>> {}
 	
 	pub type VectorOfVectorOfMat = core::Vector::<core::Vector::<core::Mat>>;
 	
@@ -2139,6 +2165,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<core::Point> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<core::Point>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<core::Point>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOfPoint {
 		#[inline]
@@ -2210,6 +2237,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<core::Point2f> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<core::Point2f>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<core::Point2f>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOfPoint2f {
 		#[inline]
@@ -2281,6 +2309,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<core::Point3d> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<core::Point3d>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<core::Point3d>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOfPoint3d {
 		#[inline]
@@ -2352,6 +2381,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<core::Point3f> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<core::Point3f>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<core::Point3f>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOfPoint3f {
 		#[inline]
@@ -2423,6 +2453,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<core::Point3i> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<core::Point3i>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<core::Point3i>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOfPoint3i {
 		#[inline]
@@ -2494,6 +2525,7 @@ mod core_types {
 	vector_non_copy_or_bool! { core::Vector::<core::Range> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<core::Range>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<core::Range>> {}
 	
 	pub type VectorOfVectorOfVec2i = core::Vector::<core::Vector::<core::Vec2i>>;
 	
@@ -2514,6 +2546,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<core::Vec2i> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<core::Vec2i>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<core::Vec2i>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOfVec2i {
 		#[inline]
@@ -2585,6 +2618,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<f64> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<f64>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<f64>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOff64 {
 		#[inline]
@@ -2656,6 +2690,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<i32> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<i32>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<i32>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOfi32 {
 		#[inline]
@@ -2727,6 +2762,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<i8> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<i8>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<i8>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOfi8 {
 		#[inline]
@@ -2798,6 +2834,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone core::Vector::<u8> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<u8>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<u8>> {}
 	
 	impl core::ToInputArray for VectorOfVectorOfu8 {
 		#[inline]
@@ -2869,6 +2906,7 @@ mod core_types {
 	vector_non_copy_or_bool! { clone bool }
 	
 	unsafe impl Send for core::Vector::<bool> {}
+	unsafe impl Sync for core::Vector::<bool> {}
 	
 	pub type VectorOff32 = core::Vector::<f32>;
 	
@@ -2892,6 +2930,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<f32> {}
+	unsafe impl Sync for core::Vector::<f32> {}
 	
 	impl core::ToInputArray for VectorOff32 {
 		#[inline]
@@ -2966,6 +3005,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<f64> {}
+	unsafe impl Sync for core::Vector::<f64> {}
 	
 	impl core::ToInputArray for VectorOff64 {
 		#[inline]
@@ -3040,6 +3080,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<i32> {}
+	unsafe impl Sync for core::Vector::<i32> {}
 	
 	impl core::ToInputArray for VectorOfi32 {
 		#[inline]
@@ -3114,6 +3155,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<i8> {}
+	unsafe impl Sync for core::Vector::<i8> {}
 	
 	impl core::ToInputArray for VectorOfi8 {
 		#[inline]
@@ -3188,6 +3230,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<size_t> {}
+	unsafe impl Sync for core::Vector::<size_t> {}
 	
 	pub type VectorOfu8 = core::Vector::<u8>;
 	
@@ -3211,6 +3254,7 @@ mod core_types {
 	}
 	
 	unsafe impl Send for core::Vector::<u8> {}
+	unsafe impl Sync for core::Vector::<u8> {}
 	
 	impl core::ToInputArray for VectorOfu8 {
 		#[inline]
@@ -5456,8 +5500,6 @@ mod dnn_types {
 	}
 	vector_non_copy_or_bool! { core::Ptr::<crate::dnn::BackendNode> }
 	
-	unsafe impl Send for core::Vector::<core::Ptr::<crate::dnn::BackendNode>> {}
-	
 	pub type VectorOfPtrOfBackendWrapper = core::Vector::<core::Ptr::<dyn crate::dnn::BackendWrapper>>;
 	
 	impl VectorOfPtrOfBackendWrapper {
@@ -5476,8 +5518,6 @@ mod dnn_types {
 	}
 	vector_non_copy_or_bool! { core::Ptr::<dyn crate::dnn::BackendWrapper> }
 	
-	unsafe impl Send for core::Vector::<core::Ptr::<dyn crate::dnn::BackendWrapper>> {}
-	
 	pub type VectorOfPtrOfLayer = core::Vector::<core::Ptr::<crate::dnn::Layer>>;
 	
 	impl VectorOfPtrOfLayer {
@@ -5496,8 +5536,6 @@ mod dnn_types {
 	}
 	vector_non_copy_or_bool! { core::Ptr::<crate::dnn::Layer> }
 	
-	unsafe impl Send for core::Vector::<core::Ptr::<crate::dnn::Layer>> {}
-	
 	pub type VectorOfTarget = core::Vector::<crate::dnn::Target>;
 	
 	impl VectorOfTarget {
@@ -5520,6 +5558,7 @@ mod dnn_types {
 	}
 	
 	unsafe impl Send for core::Vector::<crate::dnn::Target> {}
+	unsafe impl Sync for core::Vector::<crate::dnn::Target> {}
 	
 	pub type VectorOfVectorOfMatShape = core::Vector::<core::Vector::<crate::dnn::MatShape>>;
 	
@@ -5540,6 +5579,7 @@ mod dnn_types {
 	vector_non_copy_or_bool! { core::Vector::<crate::dnn::MatShape> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<crate::dnn::MatShape>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<crate::dnn::MatShape>> {}
 	
 }
 #[cfg(ocvrs_has_module_dnn)]
@@ -6456,6 +6496,7 @@ mod flann_types {
 	}
 	
 	unsafe impl Send for core::Vector::<crate::flann::FlannIndexType> {}
+	unsafe impl Sync for core::Vector::<crate::flann::FlannIndexType> {}
 	
 }
 #[cfg(ocvrs_has_module_flann)]
@@ -6916,6 +6957,7 @@ mod line_descriptor_types {
 	}
 	
 	unsafe impl Send for core::Vector::<crate::line_descriptor::KeyLine> {}
+	unsafe impl Sync for core::Vector::<crate::line_descriptor::KeyLine> {}
 	
 	pub type VectorOfVectorOfKeyLine = core::Vector::<core::Vector::<crate::line_descriptor::KeyLine>>;
 	
@@ -6936,6 +6978,7 @@ mod line_descriptor_types {
 	vector_non_copy_or_bool! { clone core::Vector::<crate::line_descriptor::KeyLine> }
 	
 	unsafe impl Send for core::Vector::<core::Vector::<crate::line_descriptor::KeyLine>> {}
+	unsafe impl Sync for core::Vector::<core::Vector::<crate::line_descriptor::KeyLine>> {}
 	
 }
 #[cfg(ocvrs_has_module_line_descriptor)]
@@ -7034,8 +7077,6 @@ mod mcc_types {
 	}
 	vector_non_copy_or_bool! { core::Ptr::<dyn crate::mcc::MCC_CChecker> }
 	
-	unsafe impl Send for core::Vector::<core::Ptr::<dyn crate::mcc::MCC_CChecker>> {}
-	
 }
 #[cfg(ocvrs_has_module_mcc)]
 pub use mcc_types::*;
@@ -8754,6 +8795,7 @@ mod rgbd_types {
 	}
 	
 	unsafe impl Send for core::Vector::<crate::rgbd::Linemod_Feature> {}
+	unsafe impl Sync for core::Vector::<crate::rgbd::Linemod_Feature> {}
 	
 	pub type VectorOfLinemod_Match = core::Vector::<crate::rgbd::Linemod_Match>;
 	
@@ -8813,8 +8855,6 @@ mod rgbd_types {
 	}
 	vector_non_copy_or_bool! { core::Ptr::<dyn crate::rgbd::Linemod_Modality> }
 	
-	unsafe impl Send for core::Vector::<core::Ptr::<dyn crate::rgbd::Linemod_Modality>> {}
-	
 }
 #[cfg(ocvrs_has_module_rgbd)]
 pub use rgbd_types::*;
@@ -9312,6 +9352,7 @@ mod stereo_types {
 	}
 	
 	unsafe impl Send for core::Vector::<crate::stereo::MatchQuasiDense> {}
+	unsafe impl Sync for core::Vector::<crate::stereo::MatchQuasiDense> {}
 	
 }
 #[cfg(ocvrs_has_module_stereo)]
@@ -10790,8 +10831,6 @@ mod surface_matching_types {
 	}
 	vector_non_copy_or_bool! { crate::surface_matching::Pose3DPtr }
 	
-	unsafe impl Send for core::Vector::<crate::surface_matching::Pose3DPtr> {}
-	
 }
 #[cfg(ocvrs_has_module_surface_matching)]
 pub use surface_matching_types::*;
@@ -11370,6 +11409,7 @@ mod videoio_types {
 	}
 	
 	unsafe impl Send for core::Vector::<crate::videoio::VideoCaptureAPIs> {}
+	unsafe impl Sync for core::Vector::<crate::videoio::VideoCaptureAPIs> {}
 	
 }
 #[cfg(ocvrs_has_module_videoio)]
@@ -1615,3 +1615,4 @@ impl VideoWriter {
 	}
 	
 }
+pub use crate::manual::videoio::*;
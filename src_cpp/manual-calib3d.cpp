@@ -0,0 +1,33 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/calib3d.hpp>
+
+template struct Result<bool>;
+template struct Result<int>;
+
+extern "C" {
+	// the overload set of findCirclesGrid() differs between OpenCV versions, this one uses the default SimpleBlobDetector
+	Result<bool> cv_manual_findCirclesGrid(const cv::_InputArray* image, cv::Size* pattern_size, const cv::_OutputArray* centers, int flags) {
+		try {
+			return Ok<bool>(cv::findCirclesGrid(*image, *pattern_size, *centers, flags));
+		} OCVRS_CATCH(Result<bool>)
+	}
+
+	// rvecs and tvecs are returned as Nx1 CV_64FC3, OutputArrayOfArrays doesn't accept Mat in solvePnPGeneric()
+	Result<int> cv_manual_solvePnPGeneric(const cv::_InputArray* object_points, const cv::_InputArray* image_points, const cv::_InputArray* camera_matrix, const cv::_InputArray* dist_coeffs, int flags, cv::Mat* rvecs, cv::Mat* tvecs) {
+		try {
+			#if CV_VERSION_MAJOR > 4 || (CV_VERSION_MAJOR == 4 && CV_VERSION_MINOR >= 1) || (CV_VERSION_MAJOR == 3 && CV_VERSION_MINOR == 4 && CV_VERSION_REVISION >= 7)
+				std::vector<cv::Mat> rvec_list, tvec_list;
+				int solutions = cv::solvePnPGeneric(*object_points, *image_points, *camera_matrix, *dist_coeffs, rvec_list, tvec_list, false, static_cast<cv::SolvePnPMethod>(flags));
+				rvecs->create(solutions, 1, CV_64FC3);
+				tvecs->create(solutions, 1, CV_64FC3);
+				for (int i = 0; i < solutions; i++) {
+					rvec_list[i].reshape(3, 1).convertTo(rvecs->row(i), CV_64F);
+					tvec_list[i].reshape(3, 1).convertTo(tvecs->row(i), CV_64F);
+				}
+				return Ok<int>(solutions);
+			#else
+				CV_Error(cv::Error::StsNotImplemented, "solvePnPGeneric() requires OpenCV 3.4.7 or 4.1.0");
+			#endif
+		} OCVRS_CATCH(Result<int>)
+	}
+}
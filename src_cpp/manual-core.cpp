@@ -22,13 +22,13 @@ template<typename T> inline Result<void*> ocvrs_input_output_array(T* instance)
 	} OCVRS_CATCH(Result<void*>)
 }
 
-#define ocvrs_matx(base) \
-	Result<void*> cv_##base##f_input_array(const cv::base##f* instance) { return ocvrs_input_array(instance); } \
-	Result<void*> cv_##base##f_output_array(cv::base##f* instance) { return ocvrs_output_array(instance); } \
-	Result<void*> cv_##base##f_input_output_array(cv::base##f* instance) { return ocvrs_input_output_array(instance); } \
-	Result<void*> cv_##base##d_input_array(const cv::base##d* instance) { return ocvrs_input_array(instance); } \
-	Result<void*> cv_##base##d_output_array(cv::base##d* instance) { return ocvrs_output_array(instance); } \
-	Result<void*> cv_##base##d_input_output_array(cv::base##d* instance) { return ocvrs_input_output_array(instance); }
+// cv::_InputArray::init() is protected so this is the only way to construct an array over Matx of any size
+class ocvrs_MatxArray : public cv::_InputOutputArray {
+public:
+	ocvrs_MatxArray(int flags, void* instance, int rows, int cols) {
+		init(cv::_InputArray::FIXED_TYPE + cv::_InputArray::FIXED_SIZE + cv::_InputArray::MATX + flags, instance, cv::Size(cols, rows));
+	}
+};
 
 extern "C" {
 	Result<cv::Size> cv_manual_Mat_size(const cv::Mat* instance) {
@@ -82,25 +82,21 @@ extern "C" {
 
 	Result<void*> cv_Scalar_input_array(cv::Scalar* instance) { return ocvrs_input_array(instance); }
 
-	ocvrs_matx(Matx12)
-	ocvrs_matx(Matx13)
-	ocvrs_matx(Matx14)
-	ocvrs_matx(Matx16)
-
-	ocvrs_matx(Matx21)
-	ocvrs_matx(Matx31)
-	ocvrs_matx(Matx41)
-	ocvrs_matx(Matx61)
-
-	ocvrs_matx(Matx22)
-	ocvrs_matx(Matx23)
-	ocvrs_matx(Matx32)
-
-	ocvrs_matx(Matx33)
+	Result<void*> cv_manual_Matx_input_array(const void* instance, int type, int rows, int cols) {
+		try {
+			return Ok<void*>(new cv::_InputArray(ocvrs_MatxArray(type + cv::ACCESS_READ, const_cast<void*>(instance), rows, cols)));
+		} OCVRS_CATCH(Result<void*>)
+	}
 
-	ocvrs_matx(Matx34)
-	ocvrs_matx(Matx43)
+	Result<void*> cv_manual_Matx_output_array(void* instance, int type, int rows, int cols) {
+		try {
+			return Ok<void*>(new cv::_OutputArray(ocvrs_MatxArray(type + cv::ACCESS_WRITE, instance, rows, cols)));
+		} OCVRS_CATCH(Result<void*>)
+	}
 
-	ocvrs_matx(Matx44)
-	ocvrs_matx(Matx66)
+	Result<void*> cv_manual_Matx_input_output_array(void* instance, int type, int rows, int cols) {
+		try {
+			return Ok<void*>(new cv::_InputOutputArray(ocvrs_MatxArray(type + cv::ACCESS_RW, instance, rows, cols)));
+		} OCVRS_CATCH(Result<void*>)
+	}
 }
@@ -0,0 +1,20 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/highgui.hpp>
+
+template struct Result<int>;
+
+extern "C" {
+	Result_void cv_manual_setMouseCallback(const char* winname, cv::MouseCallback on_mouse, void* userdata) {
+		try {
+			cv::setMouseCallback(winname, on_mouse, userdata);
+			return Ok();
+		} OCVRS_CATCH(Result_void)
+	}
+
+	// value pointer is not passed, the position is managed by OpenCV and accessed via getTrackbarPos()/setTrackbarPos()
+	Result<int> cv_manual_createTrackbar(const char* trackbarname, const char* winname, int count, cv::TrackbarCallback on_change, void* userdata) {
+		try {
+			return Ok<int>(cv::createTrackbar(trackbarname, winname, 0, count, on_change, userdata));
+		} OCVRS_CATCH(Result<int>)
+	}
+}
@@ -0,0 +1,88 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/imgcodecs.hpp>
+
+#if CV_VERSION_MAJOR > 4 || (CV_VERSION_MAJOR == 4 && CV_VERSION_MINOR >= 7)
+	#define OCVRS_HAS_IMAGE_COLLECTION
+#elif CV_VERSION_MAJOR == 4 && (CV_VERSION_MINOR > 5 || (CV_VERSION_MINOR == 5 && CV_VERSION_REVISION >= 5))
+	#define OCVRS_HAS_IMCOUNT
+#endif
+
+template struct Result<void*>;
+template struct Result<size_t>;
+
+// Reads the pages of a multi-page image one by one without keeping the decoded ones in memory
+class ocvrs_ImagePages {
+public:
+#if defined(OCVRS_HAS_IMAGE_COLLECTION)
+	ocvrs_ImagePages(const char* filename, int flags) : collection(filename, flags) {}
+
+	size_t size() const {
+		return collection.size();
+	}
+
+	cv::Mat read(size_t index) {
+		cv::Mat out = collection.at((int)index);
+		// the collection caches every decoded page, the caller owns the page from now on
+		collection.releaseCache((int)index);
+		return out;
+	}
+
+private:
+	cv::ImageCollection collection;
+#elif defined(OCVRS_HAS_IMCOUNT)
+	ocvrs_ImagePages(const char* filename, int flags) : filename(filename), flags(flags), count(cv::imcount(filename, flags)) {}
+
+	size_t size() const {
+		return count;
+	}
+
+	cv::Mat read(size_t index) {
+		std::vector<cv::Mat> mats;
+		if (!cv::imreadmulti(filename, mats, (int)index, 1, flags) || mats.empty()) {
+			CV_Error(cv::Error::StsError, "Can't decode the page");
+		}
+		return mats[0];
+	}
+
+private:
+	std::string filename;
+	int flags;
+	size_t count;
+#else
+	ocvrs_ImagePages(const char*, int) {
+		CV_Error(cv::Error::StsNotImplemented, "Reading pages one by one requires OpenCV 4.5.5 or newer");
+	}
+
+	size_t size() const {
+		return 0;
+	}
+
+	cv::Mat read(size_t) {
+		return cv::Mat();
+	}
+#endif
+};
+
+extern "C" {
+	Result<void*> cv_manual_ImagePages_new(const char* filename, int flags) {
+		try {
+			return Ok<void*>(new ocvrs_ImagePages(filename, flags));
+		} OCVRS_CATCH(Result<void*>)
+	}
+
+	void cv_manual_ImagePages_delete(ocvrs_ImagePages* instance) {
+		delete instance;
+	}
+
+	Result<size_t> cv_manual_ImagePages_size(const ocvrs_ImagePages* instance) {
+		try {
+			return Ok<size_t>(instance->size());
+		} OCVRS_CATCH(Result<size_t>)
+	}
+
+	Result<void*> cv_manual_ImagePages_read(ocvrs_ImagePages* instance, size_t index) {
+		try {
+			return Ok<void*>(new cv::Mat(instance->read(index)));
+		} OCVRS_CATCH(Result<void*>)
+	}
+}
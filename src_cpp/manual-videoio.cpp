@@ -0,0 +1,24 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/videoio.hpp>
+
+template struct Result<bool>;
+
+extern "C" {
+#if !(CV_VERSION_MAJOR == 3 && CV_VERSION_MINOR == 2)
+	Result<bool> cv_manual_VideoWriter_open(cv::VideoWriter* instance, const char* filename, int api_preference, int fourcc, double fps, const cv::Size* frame_size, bool is_color, const std::vector<int>* params) {
+		try {
+#if CV_VERSION_MAJOR > 4 || (CV_VERSION_MAJOR == 4 && (CV_VERSION_MINOR > 5 || (CV_VERSION_MINOR == 5 && CV_VERSION_REVISION >= 2)))
+			std::vector<int> all_params(*params);
+			all_params.push_back(cv::VIDEOWRITER_PROP_IS_COLOR);
+			all_params.push_back(is_color);
+			return Ok<bool>(instance->open(filename, api_preference, fourcc, fps, *frame_size, all_params));
+#else
+			if (!params->empty()) {
+				CV_Error(cv::Error::StsNotImplemented, "VideoWriter parameters require OpenCV 4.5.2 or newer");
+			}
+			return Ok<bool>(instance->open(filename, api_preference, fourcc, fps, *frame_size, is_color));
+#endif
+		} OCVRS_CATCH(Result<bool>)
+	}
+#endif
+}
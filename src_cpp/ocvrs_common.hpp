@@ -24,7 +24,9 @@ catch (exc_type) { \
 }
 
 #define OCVRS_CATCH(return_type) \
-CODE_CATCH(OCVRS_TYPE(return_type), cv::Exception& e, e.code, e.what()) \
+catch (cv::Exception& e) { \
+	return ErrException<OCVRS_TYPE(return_type)>(e); \
+} \
 CODE_CATCH(OCVRS_TYPE(return_type), ..., -99999, "unspecified error in OpenCV guts")
 
 #define VEC_CATCH(return_type) \
@@ -36,20 +38,26 @@ extern "C" void* ocvrs_create_string(const char*);
 template<typename T> struct Result {
 	int error_code;
 	void* error_msg;
+	void* error_func;
+	void* error_file;
+	int error_line;
 	T result;
 };
 
 struct Result_void {
 	int error_code;
 	void* error_msg;
+	void* error_func;
+	void* error_file;
+	int error_line;
 };
 
 template<typename T> inline Result<T> Ok(T result) {
-	return Result<T> { 0, 0, result };
+	return Result<T> { 0, 0, 0, 0, 0, result };
 }
 
 inline Result_void Ok() {
-	return Result_void { 0, 0 };
+	return Result_void { 0, 0, 0, 0, 0 };
 }
 
 template<typename T> inline T Err(int code, const char* msg) {
@@ -60,4 +68,12 @@ template<typename T> inline T Err(int code, const char* msg) {
 	return ret;
 }
 
+template<typename T> inline T ErrException(const cv::Exception& e) {
+	T ret = Err<T>(e.code, e.err.c_str());
+	ret.error_func = ocvrs_create_string(e.func.c_str());
+	ret.error_file = ocvrs_create_string(e.file.c_str());
+	ret.error_line = e.line;
+	return ret;
+}
+
 #endif
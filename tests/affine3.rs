@@ -0,0 +1,69 @@
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, PI};
+
+use opencv::core::{Affine3d, Matx33d, Point3d, Vec3d, Vec4d};
+
+fn assert_close(expected: &[f64], actual: &[f64]) {
+	assert_eq!(expected.len(), actual.len());
+	for (expected, actual) in expected.iter().zip(actual) {
+		assert!((expected - actual).abs() < 1e-9, "Expected: {:?}, actual: {:?}", expected, actual);
+	}
+}
+
+fn assert_same_rotation(expected: &Affine3d, actual: &Affine3d) {
+	let (expected, actual) = (expected.rotation(), actual.rotation());
+	for row in 0..3 {
+		assert_close(&expected.val[row], &actual.val[row]);
+	}
+}
+
+#[test]
+fn affine3_compose_invert() {
+	let a = Affine3d::from_rvec(Vec3d::from([0.1, -0.2, 0.3]), Vec3d::from([1., 2., 3.]));
+	let b = Affine3d::from_euler(0.4, -0.1, 1.2, Vec3d::from([-0.5, 0., 0.25]));
+	let pt = Point3d::new(0.3, -1.2, 2.5);
+
+	let composed = a * b;
+	let expected = a * (b * pt);
+	let actual = composed * pt;
+	assert_close(&[expected.x, expected.y, expected.z], &[actual.x, actual.y, actual.z]);
+	assert_eq!(composed, b.concatenate(&a));
+
+	let inv = a.inv().expect("Rigid transformation must be invertible");
+	let roundtrip = inv * (a * pt);
+	assert_close(&[pt.x, pt.y, pt.z], &[roundtrip.x, roundtrip.y, roundtrip.z]);
+	let identity = a * inv;
+	assert_same_rotation(&Affine3d::identity(), &identity);
+	assert_close(&[0., 0., 0.], &*identity.translation());
+
+	assert!(Affine3d::new(Matx33d::from([[1., 0., 0.], [0., 1., 0.], [0., 0., 0.]]), Vec3d::all(0.)).inv().is_none());
+}
+
+#[test]
+fn affine3_rotation_conversions() {
+	let translation = Vec3d::from([1., -2., 0.5]);
+	for &rvec in &[[0.1, -0.2, 0.3], [0., 0., 0.], [PI, 0., 0.], [0., -PI * FRAC_1_SQRT_2, PI * FRAC_1_SQRT_2], [1e-12, 0., 0.]] {
+		let pose = Affine3d::from_rvec(Vec3d::from(rvec), translation);
+		assert_close(&*translation, &*pose.translation());
+		assert_same_rotation(&pose, &Affine3d::from_rvec(pose.rvec(), translation));
+		assert_same_rotation(&pose, &Affine3d::from_quaternion(pose.quaternion(), translation));
+		let (roll, pitch, yaw) = pose.euler();
+		assert_same_rotation(&pose, &Affine3d::from_euler(roll, pitch, yaw, translation));
+	}
+
+	let rvec = Affine3d::from_rvec(Vec3d::from([0.1, -0.2, 0.3]), translation).rvec();
+	assert_close(&[0.1, -0.2, 0.3], &*rvec);
+
+	// 90 degrees around Z
+	let pose = Affine3d::from_quaternion(Vec4d::from([1., 0., 0., 1.]), Vec3d::all(0.));
+	assert_close(&[FRAC_1_SQRT_2, 0., 0., FRAC_1_SQRT_2], &*pose.quaternion());
+	let pt = pose * Point3d::new(1., 0., 0.);
+	assert_close(&[0., 1., 0.], &[pt.x, pt.y, pt.z]);
+	let (roll, pitch, yaw) = pose.euler();
+	assert_close(&[0., 0., FRAC_PI_2], &[roll, pitch, yaw]);
+
+	// gimbal lock
+	let pose = Affine3d::from_euler(0.3, FRAC_PI_2, 0.5, Vec3d::all(0.));
+	let (roll, pitch, yaw) = pose.euler();
+	assert_close(&[0., FRAC_PI_2], &[roll, pitch]);
+	assert_same_rotation(&pose, &Affine3d::from_euler(roll, pitch, yaw, Vec3d::all(0.)));
+}
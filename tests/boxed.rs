@@ -1,6 +1,8 @@
 use std::{
 	ffi::c_void,
 	mem::transmute,
+	sync::Arc,
+	thread,
 };
 
 use opencv::{
@@ -8,7 +10,7 @@ use opencv::{
 	flann::IndexParams,
 	prelude::*,
 	Result,
-	types::{PtrOfIndexParams, VectorOfVec4f},
+	types::{PtrOfIndexParams, PtrOff32, VectorOfMat, VectorOfVec4f},
 };
 
 #[test]
@@ -62,8 +64,6 @@ fn into_raw() -> Result<()> {
 
 #[test]
 fn into_raw_ptroff32() -> Result<()> {
-	use opencv::types::PtrOff32;
-
 	#[inline(never)]
 	fn into_raw(a: PtrOff32) -> *mut c_void {
 		a.into_raw()
@@ -100,3 +100,46 @@ fn into_raw_ptrofboxed() -> Result<()> {
 	assert_eq!("my string", b.get_string("string", "-")?);
 	Ok(())
 }
+
+#[test]
+fn thread_safety() -> Result<()> {
+	fn assert_send<T: Send>() {}
+	fn assert_sync<T: Sync>() {}
+
+	assert_send::<IndexParams>();
+	assert_send::<Mat>();
+	assert_send::<VectorOfMat>();
+	assert_sync::<VectorOfVec4f>();
+	assert_sync::<PtrOff32>();
+
+	let mat = Mat::new_rows_cols_with_default(10, 10, u16::typ(), Scalar::all(9.))?;
+	let total = (0..4)
+		.map(|_| {
+			let mat = mat.try_clone()?;
+			Ok(thread::spawn(move || mat.total()))
+		})
+		.collect::<Result<Vec<_>>>()?
+		.into_iter()
+		.map(|h| h.join().unwrap())
+		.collect::<Result<Vec<_>>>()?;
+	assert_eq!(vec![100; 4], total);
+
+	let vec = Arc::new(VectorOfVec4f::from_iter(vec![Vec4f::all(1.); 10]));
+	let len = (0..4)
+		.map(|_| {
+			let vec = Arc::clone(&vec);
+			thread::spawn(move || vec.len())
+		})
+		.collect::<Vec<_>>()
+		.into_iter()
+		.map(|h| h.join().unwrap())
+		.collect::<Vec<_>>();
+	assert_eq!(vec![10; 4], len);
+
+	let mut params = IndexParams::default()?;
+	params.set_int("int", 87)?;
+	let params = thread::spawn(move || params).join().unwrap();
+	let params = PtrOfIndexParams::new(params);
+	assert_eq!(87, params.get_int("int", 3)?);
+	Ok(())
+}
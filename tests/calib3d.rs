@@ -0,0 +1,243 @@
+#![cfg(ocvrs_has_module_calib3d)]
+
+use opencv::{
+	calib3d::{self, CalibrationSession, CalibrationSessionParams, CalibrationTarget, CameraIntrinsics, DistortionModel, FrameOutcome, PnpMethod, Pose, Undistorter},
+	core::{self, Affine3d, Mat, Matx33d, Point2f, Point3f, Rect, Scalar, Size, TermCriteria, Vec3d, Vector},
+	imgproc,
+	prelude::*,
+	Result,
+};
+
+const IMAGE_SIZE: Size = Size { width: 640, height: 480 };
+
+fn camera_matrix() -> Matx33d {
+	Matx33d::from([[800., 0., 320.], [0., 800., 240.], [0., 0., 1.]])
+}
+
+fn chessboard_points() -> Vector<Point3f> {
+	(0..6)
+		.flat_map(|y| (0..9).map(move |x| Point3f::new(x as f32 * 0.1, y as f32 * 0.1, 0.)))
+		.collect()
+}
+
+/// Projects a planar target in several poses with the known intrinsics, the camera is shifted by `offset_x` along x
+fn synthetic_views(dist_coeffs: &DistortionModel, offset_x: f64) -> Result<(Vector<Vector<Point3f>>, Vector<Vector<Point2f>>)> {
+	let poses = [
+		([0.1, -0.2, 0.05], [-0.4, -0.25, 2.]),
+		([-0.3, 0.1, -0.1], [-0.3, -0.3, 2.5]),
+		([0.25, 0.3, 0.2], [-0.5, -0.2, 1.8]),
+		([-0.15, -0.35, 0.], [-0.35, -0.2, 2.2]),
+		([0.4, 0., -0.3], [-0.45, -0.3, 2.4]),
+		([0., 0.45, 0.1], [-0.4, -0.25, 2.1]),
+	];
+	let mut object_points = Vector::new();
+	let mut image_points = Vector::new();
+	for &(rvec, [tx, ty, tz]) in poses.iter() {
+		let tvec = [tx - offset_x, ty, tz];
+		let object = chessboard_points();
+		let mut image = Vector::<Point2f>::new();
+		calib3d::project_points(&object, &Mat::from_slice(&rvec)?, &Mat::from_slice(&tvec)?, &camera_matrix(), &dist_coeffs.to_mat()?, &mut image, &mut core::no_array()?, 0.)?;
+		object_points.push(object);
+		image_points.push(image);
+	}
+	Ok((object_points, image_points))
+}
+
+#[test]
+fn distortion_model() -> Result<()> {
+	assert_eq!(DistortionModel::Brown5([0.1, 0.2, 0., 0., 0.]), DistortionModel::from_brown_coeffs(&[0.1, 0.2, 0., 0.])?);
+	assert!(matches!(DistortionModel::from_brown_coeffs(&[0.; 8])?, DistortionModel::Brown8(_)));
+	assert!(DistortionModel::from_brown_coeffs(&[0.; 6]).is_err());
+	assert!(DistortionModel::from_fisheye_coeffs(&[0.; 4])?.is_fisheye());
+	assert!(DistortionModel::from_fisheye_coeffs(&[0.; 5]).is_err());
+	let model = DistortionModel::Brown5([0.1, -0.05, 0.001, 0.002, 0.01]);
+	assert_eq!(model, DistortionModel::from_mat(&model.to_mat()?, false)?);
+	Ok(())
+}
+
+#[test]
+fn calibrate_camera() -> Result<()> {
+	let (object_points, image_points) = synthetic_views(&DistortionModel::Brown5([0.; 5]), 0.)?;
+	let criteria = TermCriteria::new(core::TermCriteria_Type::COUNT as i32 + core::TermCriteria_Type::EPS as i32, 100, f64::EPSILON)?;
+	let res = CameraIntrinsics::calibrate(&object_points, &image_points, IMAGE_SIZE, 0, criteria)?;
+	assert!(res.rms < 1e-3);
+	assert!((res.intrinsics.fx() - 800.).abs() < 1.);
+	assert!((res.intrinsics.cy() - 240.).abs() < 1.);
+	assert!(matches!(res.intrinsics.dist_coeffs, DistortionModel::Brown5(_)));
+	assert_eq!(IMAGE_SIZE, res.intrinsics.image_size);
+	assert_eq!(object_points.len(), res.per_view_errors.len());
+	assert_eq!(object_points.len(), res.rvecs.len());
+	assert!(res.per_view_errors.iter().all(|&err| err < 1e-3));
+
+	let res = CameraIntrinsics::calibrate(&object_points, &image_points, IMAGE_SIZE, calib3d::CALIB_RATIONAL_MODEL, criteria)?;
+	assert!(matches!(res.intrinsics.dist_coeffs, DistortionModel::Brown8(_)));
+
+	assert!(CameraIntrinsics::calibrate(&object_points, &Vector::new(), IMAGE_SIZE, 0, criteria).is_err());
+	Ok(())
+}
+
+#[test]
+fn calibrate_fisheye() -> Result<()> {
+	let (object_points, image_points) = synthetic_views(&DistortionModel::Brown5([0.; 5]), 0.)?;
+	let criteria = TermCriteria::new(core::TermCriteria_Type::COUNT as i32 + core::TermCriteria_Type::EPS as i32, 100, f64::EPSILON)?;
+	let res = CameraIntrinsics::calibrate_fisheye(&object_points, &image_points, IMAGE_SIZE, calib3d::Fisheye_CALIB_RECOMPUTE_EXTRINSIC, criteria)?;
+	assert!(res.intrinsics.dist_coeffs.is_fisheye());
+	assert_eq!(object_points.len(), res.per_view_errors.len());
+	Ok(())
+}
+
+#[cfg(not(ocvrs_opencv_branch_32))]
+#[test]
+fn stereo_calibrate() -> Result<()> {
+	use opencv::calib3d::StereoExtrinsics;
+
+	let (object_points, image_points1) = synthetic_views(&DistortionModel::Brown5([0.; 5]), 0.)?;
+	let (_, image_points2) = synthetic_views(&DistortionModel::Brown5([0.; 5]), 0.1)?;
+	let intrinsics = CameraIntrinsics { camera_matrix: camera_matrix(), dist_coeffs: DistortionModel::Brown5([0.; 5]), image_size: IMAGE_SIZE };
+	let criteria = TermCriteria::new(core::TermCriteria_Type::COUNT as i32 + core::TermCriteria_Type::EPS as i32, 100, 1e-6)?;
+	let res = StereoExtrinsics::calibrate(&object_points, &image_points1, &image_points2, &intrinsics, &intrinsics, calib3d::CALIB_FIX_INTRINSIC, criteria)?;
+	assert_eq!(object_points.len(), res.per_view_errors.len());
+	assert!(res.rms < 1e-3);
+	assert!((res.extrinsics.translation[0] + 0.1).abs() < 1e-3);
+	assert_eq!(intrinsics.camera_matrix, res.first.camera_matrix);
+
+	let second = CameraIntrinsics { image_size: Size::new(IMAGE_SIZE.width / 2, IMAGE_SIZE.height / 2), ..intrinsics };
+	let err = StereoExtrinsics::calibrate(&object_points, &image_points1, &image_points2, &intrinsics, &second, calib3d::CALIB_FIX_INTRINSIC, criteria).unwrap_err();
+	assert_eq!(core::StsUnmatchedSizes, err.code);
+	Ok(())
+}
+
+#[test]
+fn calibration_session_detect() -> Result<()> {
+	let mut image = Mat::new_rows_cols_with_default(IMAGE_SIZE.height, IMAGE_SIZE.width, u8::typ(), Scalar::all(255.))?;
+	for row in 0..7 {
+		for col in 0..10 {
+			if (row + col) % 2 == 0 {
+				imgproc::rectangle(&mut image, Rect::new(120 + col * 40, 100 + row * 40, 40, 40), Scalar::all(0.), imgproc::FILLED, imgproc::LINE_8, 0)?;
+			}
+		}
+	}
+	let target = CalibrationTarget::Chessboard { pattern_size: Size::new(9, 6), square_size: 0.1 };
+	let mut session = CalibrationSession::new(target, IMAGE_SIZE, CalibrationSessionParams::default())?;
+	assert_eq!(FrameOutcome::Accepted { view: 0, points: 54 }, session.add_frame(&image)?);
+	assert_eq!(FrameOutcome::TooSimilar { view: 0 }, session.add_frame(&image)?);
+	let blank = Mat::new_rows_cols_with_default(IMAGE_SIZE.height, IMAGE_SIZE.width, u8::typ(), Scalar::all(255.))?;
+	assert_eq!(FrameOutcome::NotFound, session.add_frame(&blank)?);
+	assert!(session.add_frame(&Mat::new_rows_cols_with_default(10, 10, u8::typ(), Scalar::all(255.))?).is_err());
+	assert_eq!(1, session.view_count());
+	let corner = session.image_points().get(0)?.get(0)?;
+	// the detection can start from either end of the board
+	assert!([160., 480.].iter().any(|x| (corner.x - x).abs() < 1.));
+	assert!([140., 340.].iter().any(|y| (corner.y - y).abs() < 1.));
+	Ok(())
+}
+
+#[test]
+fn calibration_session_finish() -> Result<()> {
+	let (object_points, image_points) = synthetic_views(&DistortionModel::Brown5([0.; 5]), 0.)?;
+	let target = CalibrationTarget::Chessboard { pattern_size: Size::new(9, 6), square_size: 0.1 };
+	let mut session = CalibrationSession::new(target, IMAGE_SIZE, CalibrationSessionParams::default())?;
+	for (i, (object, image)) in object_points.iter().zip(image_points.iter()).enumerate() {
+		assert_eq!(FrameOutcome::Accepted { view: i, points: 54 }, session.add_points(image, object)?);
+	}
+	assert_eq!(FrameOutcome::TooSimilar { view: 2 }, session.add_points(image_points.get(2)?, object_points.get(2)?)?);
+	let few = (0..3).map(|i| image_points.get(0)?.get(i)).collect::<Result<Vector<_>>>()?;
+	let few_object = (0..3).map(|i| object_points.get(0)?.get(i)).collect::<Result<Vector<_>>>()?;
+	assert_eq!(FrameOutcome::TooFewPoints(3), session.add_points(few, few_object)?);
+	assert!(session.coverage() > 0. && session.coverage() < 1.);
+	assert_eq!(((1. - session.coverage()) * 48.).round() as usize, session.uncovered_cells().len());
+
+	// a view with large detection errors
+	let mut noisy = Vector::<Point2f>::new();
+	calib3d::project_points(&chessboard_points(), &Mat::from_slice(&[0.2, -0.1, 0.3])?, &Mat::from_slice(&[-0.4, -0.2, 2.3])?, &camera_matrix(), &core::no_array()?, &mut noisy, &mut core::no_array()?, 0.)?;
+	let noisy = noisy.iter()
+		.enumerate()
+		.map(|(i, p)| if i % 2 == 0 { Point2f::new(p.x + 5., p.y - 5.) } else { Point2f::new(p.x - 5., p.y + 5.) })
+		.collect::<Vector<_>>();
+	assert_eq!(FrameOutcome::Accepted { view: 6, points: 54 }, session.add_points(noisy, chessboard_points())?);
+
+	let criteria = TermCriteria::new(core::TermCriteria_Type::COUNT as i32 + core::TermCriteria_Type::EPS as i32, 100, f64::EPSILON)?;
+	let res = session.finish(0, criteria)?;
+	assert_eq!(vec![6], res.outliers);
+	assert_eq!(vec![0, 1, 2, 3, 4, 5], res.views);
+	assert!(res.calibration.rms < 1e-3);
+	assert!((res.calibration.intrinsics.fx() - 800.).abs() < 1.);
+
+	let empty = CalibrationSession::new(target, IMAGE_SIZE, CalibrationSessionParams::default())?;
+	assert!(empty.finish(0, criteria).is_err());
+	Ok(())
+}
+
+#[test]
+fn undistorter() -> Result<()> {
+	let points = (0..5)
+		.flat_map(|y| (0..5).map(move |x| Point2f::new(120. + x as f32 * 100., 80. + y as f32 * 80.)))
+		.collect::<Vector<_>>();
+	let models = [
+		DistortionModel::Brown5([-0.2, 0.05, 0.001, 0.001, 0.]),
+		DistortionModel::Fisheye([0.05, -0.01, 0.002, 0.]),
+	];
+	for &dist_coeffs in models.iter() {
+		let intrinsics = CameraIntrinsics { camera_matrix: camera_matrix(), dist_coeffs, image_size: IMAGE_SIZE };
+		let undistorter = Undistorter::new(&intrinsics, camera_matrix(), IMAGE_SIZE, false)?;
+		assert_eq!(f32::typ(), undistorter.maps().0.typ()?);
+		let distorted = undistorter.distort_points(&points)?;
+		let undistorted = undistorter.undistort_points(&distorted)?;
+		assert_eq!(points.len(), undistorted.len());
+		for (orig, roundtrip) in points.iter().zip(undistorted.iter()) {
+			assert!((orig.x - roundtrip.x).abs() < 0.01 && (orig.y - roundtrip.y).abs() < 0.01);
+		}
+		assert!(undistorter.distort_points(&Vector::new())?.is_empty());
+
+		let undistorter = Undistorter::with_alpha(&intrinsics, 0., true)?;
+		assert_eq!(core::CV_16SC2, undistorter.maps().0.typ()?);
+		let src = Mat::new_rows_cols_with_default(IMAGE_SIZE.height, IMAGE_SIZE.width, u8::typ(), Scalar::all(128.))?;
+		let mut dst = Mat::default();
+		undistorter.undistort_image(&src, &mut dst)?;
+		assert_eq!(IMAGE_SIZE, dst.size()?);
+		assert_eq!(128, *dst.at_2d::<u8>(IMAGE_SIZE.height / 2, IMAGE_SIZE.width / 2)?);
+	}
+	Ok(())
+}
+
+#[test]
+fn estimate_pose() -> Result<()> {
+	let intrinsics = CameraIntrinsics { camera_matrix: camera_matrix(), dist_coeffs: DistortionModel::Brown5([-0.1, 0.01, 0., 0., 0.]), image_size: IMAGE_SIZE };
+	let expected = Pose::from_rvec_tvec(Vec3d::from([0.1, -0.2, 0.05]), Vec3d::from([-0.4, -0.25, 2.]))?;
+	let rvec = expected.rvec()?;
+	assert!((rvec[0] - 0.1).abs() < 1e-9 && (rvec[1] + 0.2).abs() < 1e-9 && (rvec[2] - 0.05).abs() < 1e-9);
+	let affine = Affine3d::from(expected);
+	let affine_rvec = affine.rvec();
+	assert!((0..3).all(|i| (affine_rvec[i] - rvec[i]).abs() < 1e-9));
+	assert_eq!(expected, Pose::from(affine));
+
+	let object_points = chessboard_points();
+	let mut image_points = Vector::<Point2f>::new();
+	calib3d::project_points(&object_points, &Mat::from_slice(&rvec.0)?, &Mat::from_slice(&expected.translation.0)?, &camera_matrix(), &intrinsics.dist_coeffs.to_mat()?, &mut image_points, &mut core::no_array()?, 0.)?;
+	let close = |pose: &Pose| {
+		(0..3).all(|i| (pose.translation[i] - expected.translation[i]).abs() < 1e-3)
+	};
+
+	let res = calib3d::estimate_pose(&object_points, &image_points, &intrinsics, PnpMethod::default())?.expect("Pose not found");
+	assert!(close(&res.pose));
+	assert_eq!(object_points.len(), res.inliers.len());
+	assert!(res.rms < 1e-3);
+
+	let res = calib3d::estimate_pose(&object_points, &image_points, &intrinsics, PnpMethod::Generic { flags: calib3d::SOLVEPNP_IPPE })?.expect("Pose not found");
+	assert!(close(&res.pose));
+	assert!(res.rms < 1e-3);
+
+	// gross outliers are excluded from the inliers and from the error
+	let corrupted = image_points.iter()
+		.enumerate()
+		.map(|(i, p)| if i % 10 == 0 { Point2f::new(p.x + 50., p.y - 40.) } else { p })
+		.collect::<Vector<_>>();
+	let res = calib3d::estimate_pose(&object_points, &corrupted, &intrinsics, PnpMethod::ransac())?.expect("Pose not found");
+	assert!(close(&res.pose));
+	assert!(res.inliers.iter().all(|i| i % 10 != 0));
+	assert_eq!(object_points.len() - 6, res.inliers.len());
+	assert!(res.rms < 1e-2);
+
+	assert!(calib3d::estimate_pose(&object_points, &Vector::new(), &intrinsics, PnpMethod::default()).is_err());
+	Ok(())
+}
@@ -0,0 +1,41 @@
+use matches::assert_matches;
+
+use opencv::{
+	core::{self, Mat},
+	Error,
+	ErrorKind,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn error_cpp_exception() -> Result<()> {
+	let a = Mat::new_rows_cols_with_default(2, 2, u8::typ(), core::Scalar::all(1.))?;
+	let b = Mat::new_rows_cols_with_default(3, 3, u8::typ(), core::Scalar::all(1.))?;
+	let mut dst = Mat::default();
+	let err = core::add(&a, &b, &mut dst, &core::no_array()?, -1).unwrap_err();
+	assert_eq!(ErrorKind::StsUnmatchedSizes, err.kind());
+	assert_eq!(core::StsUnmatchedSizes, err.kind().code());
+	let cpp_location = err.cpp_location.as_ref().expect("Missing C++ location");
+	assert!(cpp_location.file.contains("arithm"));
+	assert!(cpp_location.line > 0);
+	assert!(!cpp_location.func.is_empty());
+	assert!(err.to_string().contains(&cpp_location.file));
+	Ok(())
+}
+
+#[test]
+fn error_rust() -> Result<()> {
+	let mat = Mat::from_slice(&[1u8, 2, 3])?;
+	let err = mat.at::<u8>(5).unwrap_err();
+	assert_matches!(err, Error { code: core::StsOutOfRange, cpp_location: None, .. });
+	assert_eq!(ErrorKind::StsOutOfRange, err.kind());
+
+	let err = Error::new(core::StsBadArg, "test".to_string());
+	assert_eq!(file!(), err.rust_location.file());
+	assert_eq!(line!() - 2, err.rust_location.line());
+
+	assert_eq!(ErrorKind::Unknown(-99999), ErrorKind::from(-99999));
+	assert_eq!(-99999, ErrorKind::Unknown(-99999).code());
+	Ok(())
+}
@@ -0,0 +1,117 @@
+#![cfg(ocvrs_has_module_highgui)]
+
+use opencv::{
+	highgui::{self, KeyCode, MouseFlags},
+	Result,
+};
+#[cfg(feature = "highgui-recording")]
+use opencv::{
+	core::{Point, Scalar, Vec3b},
+	highgui::{Event, MouseEventTypes, Recorder, Window},
+	prelude::*,
+};
+
+#[test]
+fn key_code() -> Result<()> {
+	assert_eq!(Some('q'), KeyCode::from('q').to_char());
+	assert_eq!(KeyCode::ESCAPE, KeyCode::from_code(27));
+	assert_eq!(None, KeyCode::from_code(65361).to_char());
+	Ok(())
+}
+
+#[test]
+fn mouse_flags() -> Result<()> {
+	let flags = MouseFlags::from_bits(highgui::EVENT_FLAG_LBUTTON | highgui::EVENT_FLAG_CTRLKEY | (120 << 16));
+	assert!(flags.contains(MouseFlags::LBUTTON));
+	assert!(flags.contains(MouseFlags::CTRLKEY));
+	assert!(!flags.contains(MouseFlags::SHIFTKEY));
+	assert_eq!(120, flags.wheel_delta());
+	assert_eq!(highgui::get_mouse_wheel_delta(flags.bits())?, flags.wheel_delta());
+	let flags = MouseFlags::from_bits(-120 << 16);
+	assert_eq!(-120, flags.wheel_delta());
+	Ok(())
+}
+
+#[cfg(feature = "highgui-recording")]
+#[test]
+fn recorder() -> Result<()> {
+	use std::sync::{Arc, Mutex};
+
+	let recorder = Recorder::install()?;
+	assert!(Recorder::install().is_err());
+	let mut window = Window::new("main", highgui::WINDOW_AUTOSIZE)?;
+	let changes = Arc::new(Mutex::new(vec![]));
+	window.create_trackbar_with_callback("threshold", 3, 10, {
+		let changes = Arc::clone(&changes);
+		move |pos| changes.lock().unwrap().push(pos)
+	})?;
+	assert_eq!(3, window.trackbar_pos("threshold")?);
+	window.imshow(&Mat::new_rows_cols_with_default(2, 3, Vec3b::typ(), Scalar::all(10.))?)?;
+
+	recorder.mouse_event("main", MouseEventTypes::EVENT_LBUTTONDOWN, Point::new(1, 2), MouseFlags::LBUTTON);
+	recorder.move_trackbar("main", "threshold", 20);
+	recorder.press_key('q');
+	recorder.press_key(KeyCode::ESCAPE);
+	assert_eq!(
+		vec![
+			Event::Mouse { kind: MouseEventTypes::EVENT_LBUTTONDOWN, pos: Point::new(1, 2), flags: MouseFlags::LBUTTON },
+			Event::Trackbar { name: "threshold".to_string(), pos: 10 },
+			Event::Key(KeyCode::from('q')),
+		],
+		window.poll_events(0)?,
+	);
+	assert_eq!(vec![10], *changes.lock().unwrap());
+	assert_eq!(vec![Event::Key(KeyCode::ESCAPE)], window.poll_events(0)?);
+	assert!(window.poll_events(0)?.is_empty());
+
+	assert_eq!(vec!["main".to_string()], recorder.windows());
+	let frames = recorder.take_frames();
+	assert_eq!(1, frames.len());
+	assert_eq!("main", frames[0].window);
+	assert_eq!(Vec3b::all(10), *frames[0].mat.at_2d::<Vec3b>(1, 2)?);
+	assert_eq!(0, recorder.frame_count());
+	window.destroy()?;
+	assert!(recorder.windows().is_empty());
+	Ok(())
+}
+
+#[cfg(feature = "highgui-recording")]
+#[test]
+fn callback_panic() -> Result<()> {
+	use std::panic::{self, AssertUnwindSafe};
+
+	let recorder = Recorder::install()?;
+	let mut window = Window::new("main", highgui::WINDOW_AUTOSIZE)?;
+	window.set_mouse_callback(|_, _, _| panic!("mouse callback"));
+	recorder.mouse_event("main", MouseEventTypes::EVENT_MOUSEMOVE, Point::new(1, 2), MouseFlags::from_bits(0));
+	recorder.press_key('q');
+	let payload = panic::catch_unwind(AssertUnwindSafe(|| window.poll_events(0))).unwrap_err();
+	assert_eq!(Some(&"mouse callback"), payload.downcast_ref::<&str>());
+	// the panic is only reported once
+	window.remove_mouse_callback();
+	assert!(window.poll_events(0)?.is_empty());
+	Ok(())
+}
+
+#[cfg(feature = "highgui-recording")]
+#[test]
+fn recorder_free_functions() -> Result<()> {
+	let recorder = Recorder::install()?;
+	highgui::named_window("debug", highgui::WINDOW_NORMAL)?;
+	highgui::imshow("debug", &Mat::new_rows_cols_with_default(1, 1, u8::typ(), Scalar::all(5.))?)?;
+	highgui::imshow("other", &Mat::new_rows_cols_with_default(1, 1, u8::typ(), Scalar::all(7.))?)?;
+	assert_eq!(vec!["debug".to_string(), "other".to_string()], recorder.windows());
+	recorder.press_key('a');
+	recorder.press_key(KeyCode::from_code(65361));
+	assert_eq!(i32::from(b'a'), highgui::wait_key(0)?);
+	assert_eq!(65361 & 0xff, highgui::wait_key(0)?);
+	assert_eq!(-1, highgui::wait_key_ex(0)?);
+	let frames = recorder.take_frames();
+	assert_eq!(vec!["debug", "other"], frames.iter().map(|frame| frame.window.as_str()).collect::<Vec<_>>());
+	assert_eq!(7, *frames[1].mat.at_2d::<u8>(0, 0)?);
+	highgui::destroy_window("debug")?;
+	assert_eq!(vec!["other".to_string()], recorder.windows());
+	highgui::destroy_all_windows()?;
+	assert!(recorder.windows().is_empty());
+	Ok(())
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "image")]
+
+use std::convert::TryFrom;
+
+use image::{ImageBuffer, Luma, Rgb, Rgba};
+use matches::assert_matches;
+
+use opencv::{
+	core::{self, ChannelOrder, Rect, Vec3b, Vec4f},
+	Error,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn image_from_image_buffer() -> Result<()> {
+	let buf = ImageBuffer::from_fn(3, 2, |x, y| Rgb([x as u8, y as u8, 100]));
+	let mat = Mat::from_image_buffer(&buf)?;
+	assert_eq!(Vec3b::typ(), mat.typ()?);
+	assert_eq!(2, mat.rows());
+	assert_eq!(3, mat.cols());
+	assert_eq!(Vec3b::from([100, 1, 2]), *mat.at_2d::<Vec3b>(1, 2)?);
+
+	let mat = Mat::from_image_buffer_with_order(&buf, ChannelOrder::Rgb)?;
+	assert_eq!(Vec3b::from([2, 1, 100]), *mat.at_2d::<Vec3b>(1, 2)?);
+
+	let buf = ImageBuffer::from_fn(2, 2, |x, y| Rgba([x as f32, y as f32, 0.5, 1.]));
+	let mat = Mat::from_image_buffer(&buf)?;
+	assert_eq!(Vec4f::typ(), mat.typ()?);
+	assert_eq!(Vec4f::from([0.5, 1., 1., 1.]), *mat.at_2d::<Vec4f>(1, 1)?);
+	Ok(())
+}
+
+#[test]
+fn image_to_image_buffer() -> Result<()> {
+	let buf = ImageBuffer::from_fn(4, 3, |x, y| Rgb([x as u8, y as u8, 200]));
+	let mat = Mat::from_image_buffer(&buf)?;
+	let buf_back = ImageBuffer::<Rgb<u8>, Vec<u8>>::try_from(&mat)?;
+	assert_eq!(buf, buf_back);
+
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 2, 2))?;
+	let buf_roi = roi.to_image_buffer::<Rgb<u8>>(ChannelOrder::Bgr)?;
+	assert_eq!(2, buf_roi.width());
+	assert_eq!(2, buf_roi.height());
+	assert_eq!(&Rgb([2, 1, 200]), buf_roi.get_pixel(1, 0));
+
+	let buf_rgb = roi.to_image_buffer::<Rgb<u8>>(ChannelOrder::Rgb)?;
+	assert_eq!(&Rgb([200, 1, 2]), buf_rgb.get_pixel(1, 0));
+
+	assert_matches!(ImageBuffer::<Luma<u16>, Vec<u16>>::try_from(&mat), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	Ok(())
+}
+
+#[test]
+fn image_luma() -> Result<()> {
+	let buf = ImageBuffer::from_fn(5, 5, |x, y| Luma([(x * 1000 + y) as u16]));
+	let mat = Mat::from_image_buffer(&buf)?;
+	assert_eq!(u16::typ(), mat.typ()?);
+	assert_eq!(3002, *mat.at_2d::<u16>(2, 3)?);
+	assert_eq!(buf, ImageBuffer::<Luma<u16>, Vec<u16>>::try_from(&mat)?);
+	Ok(())
+}
+
+#[test]
+fn image_oversized_container() -> Result<()> {
+	// ImageBuffer accepts containers longer than width * height * channels
+	let mut data = (0..12).collect::<Vec<u8>>();
+	data.extend_from_slice(&[255; 7]);
+	let buf = ImageBuffer::<Luma<u8>, _>::from_raw(4, 3, data).expect("Container is big enough");
+	let mat = Mat::from_image_buffer(&buf)?;
+	assert_eq!(3, mat.rows());
+	assert_eq!(4, mat.cols());
+	assert_eq!(11, *mat.at_2d::<u8>(2, 3)?);
+	assert_eq!((0..12).collect::<Vec<u8>>(), mat.data_typed::<u8>()?);
+	Ok(())
+}
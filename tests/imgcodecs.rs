@@ -1,15 +1,16 @@
 #![cfg(ocvrs_has_module_imgcodecs)]
 
-use std::ffi::c_void;
+use std::{env, ffi::c_void, fs};
 
 use opencv::{
 	core::{self, Size, Vec3b},
-	imgcodecs,
+	imgcodecs::{self, ImageFormat, ImreadFlags, JpegWriteParams, PngWriteParams, WriteParams},
 	prelude::*,
 	Result,
 };
 
 const PIXEL: &[u8] = include_bytes!("pixel.png");
+const PAGES: &[u8] = include_bytes!("pages.tiff");
 
 #[test]
 fn decode() -> Result<()> {
@@ -33,3 +34,109 @@ fn decode() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn decode_encode_slice() -> Result<()> {
+	let img = imgcodecs::decode(PIXEL, ImreadFlags::COLOR)?;
+	assert_eq!(Size::new(1, 1), img.size()?);
+	assert_eq!(Vec3b::from([56u8, 56, 191]), *img.at_2d::<Vec3b>(0, 0)?);
+	let gray = imgcodecs::decode(PIXEL, ImreadFlags::GRAYSCALE)?;
+	assert_eq!(1, gray.channels()?);
+
+	assert!(imgcodecs::decode(&[], ImreadFlags::COLOR).is_err());
+	assert!(imgcodecs::decode(b"definitely not an image", ImreadFlags::COLOR).is_err());
+
+	let png = imgcodecs::encode(&img, ImageFormat::Png { compression: 9 })?;
+	assert_eq!(b"\x89PNG", &png[..4]);
+	assert_eq!(*img.at_2d::<Vec3b>(0, 0)?, *imgcodecs::decode(&png, ImreadFlags::UNCHANGED)?.at_2d::<Vec3b>(0, 0)?);
+
+	let jpeg = imgcodecs::encode(&img, ImageFormat::Jpeg { quality: 90, progressive: true })?;
+	assert_eq!(&[0xFF, 0xD8], &jpeg[..2]);
+	assert_eq!(Size::new(1, 1), imgcodecs::decode(&jpeg, ImreadFlags::COLOR)?.size()?);
+
+	assert!(imgcodecs::encode(&img, ImageFormat::Png { compression: 10 }).is_err());
+	assert!(imgcodecs::encode(&img, ImageFormat::Jpeg { quality: 101, progressive: false }).is_err());
+	assert!(imgcodecs::encode(&img, ImageFormat::Webp { quality: 0 }).is_err());
+	Ok(())
+}
+
+#[test]
+fn typed_flags_and_params() -> Result<()> {
+	let flags = ImreadFlags::ANYDEPTH | ImreadFlags::ANYCOLOR;
+	assert!(flags.contains(ImreadFlags::ANYCOLOR));
+	assert!(!flags.contains(ImreadFlags::IGNORE_ORIENTATION));
+	assert_eq!(imgcodecs::IMREAD_ANYDEPTH | imgcodecs::IMREAD_ANYCOLOR, i32::from(flags));
+	assert_eq!(ImreadFlags::GRAYSCALE, ImreadFlags::from(imgcodecs::ImreadModes::IMREAD_GRAYSCALE));
+	assert!(!flags.contains(ImreadFlags::GRAYSCALE));
+	assert!(!flags.contains(ImreadFlags::UNCHANGED));
+	assert!(ImreadFlags::GRAYSCALE.contains(ImreadFlags::GRAYSCALE));
+	assert!(ImreadFlags::UNCHANGED.contains(ImreadFlags::UNCHANGED));
+	assert!(!ImreadFlags::UNCHANGED.contains(ImreadFlags::COLOR));
+
+	let params = JpegWriteParams { quality: Some(80), progressive: Some(true), ..JpegWriteParams::default() };
+	assert_eq!(vec![imgcodecs::IMWRITE_JPEG_QUALITY, 80, imgcodecs::IMWRITE_JPEG_PROGRESSIVE, 1], params.to_params()?.to_vec());
+	assert!(PngWriteParams::default().to_params()?.is_empty());
+	assert!(JpegWriteParams { quality: Some(-5), ..JpegWriteParams::default() }.to_params().is_err());
+	assert!(JpegWriteParams { chroma_quality: Some(1000), ..JpegWriteParams::default() }.to_params().is_err());
+	assert!(PngWriteParams { compression: Some(10), ..PngWriteParams::default() }.to_params().is_err());
+	assert_eq!(
+		ImageFormat::Jpeg { quality: 80, progressive: true }.params()?.to_vec(),
+		params.to_params()?.to_vec(),
+	);
+
+	let img = imgcodecs::imdecode_flags(&Mat::from_slice::<u8>(PIXEL)?, ImreadFlags::COLOR)?;
+	let path = env::temp_dir().join("ocvrs_typed_params.png");
+	let path = path.to_str().unwrap();
+	let params = PngWriteParams { compression: Some(9), strategy: Some(imgcodecs::ImwritePNGFlags::IMWRITE_PNG_STRATEGY_RLE), bilevel: None };
+	assert!(imgcodecs::imwrite_params(path, &img, &params)?);
+	let read = imgcodecs::imread_flags(path, ImreadFlags::GRAYSCALE)?;
+	assert_eq!(Size::new(1, 1), read.size()?);
+	assert_eq!(1, read.channels()?);
+	let mut pages = core::Vector::<Mat>::new();
+	assert!(imgcodecs::imreadmulti_flags(path, &mut pages, ImreadFlags::default())?);
+	assert_eq!(1, pages.len());
+	fs::remove_file(path).ok();
+	Ok(())
+}
+
+#[cfg(ocvrs_has_image_pages)]
+#[test]
+fn pages() -> Result<()> {
+	// 2x2 red, 3x1 green and 1x2 blue pages
+	let path = env::temp_dir().join("ocvrs_pages.tiff");
+	fs::write(&path, PAGES).unwrap();
+	let mut pages = imgcodecs::pages(&path)?;
+	assert_eq!(3, pages.page_count());
+	assert_eq!(Size::new(1, 2), pages.get(2)?.size()?);
+	assert!(pages.get(3).is_err());
+	let read = pages.collect::<Result<Vec<_>>>()?;
+	assert_eq!(vec![0, 1, 2], read.iter().map(|page| page.index).collect::<Vec<_>>());
+	let expected = [
+		(Size::new(2, 2), Vec3b::from([0, 0, 255])),
+		(Size::new(3, 1), Vec3b::from([0, 255, 0])),
+		(Size::new(1, 2), Vec3b::from([255, 0, 0])),
+	];
+	for (page, (size, color)) in read.iter().zip(expected.iter()) {
+		assert_eq!(*size, page.mat.size()?);
+		assert_eq!(Vec3b::typ(), page.mat.typ()?);
+		assert!(page.mat.data_typed::<Vec3b>()?.iter().all(|pixel| pixel == color));
+	}
+
+	let gray = imgcodecs::pages_with_flags(&path, ImreadFlags::GRAYSCALE)?.collect::<Result<Vec<_>>>()?;
+	assert_eq!(3, gray.len());
+	assert!(gray.iter().all(|page| page.mat.channels().map_or(false, |channels| channels == 1)));
+	fs::remove_file(&path).ok();
+
+	assert!(imgcodecs::pages(env::temp_dir().join("ocvrs_non_existent.tiff")).is_err());
+	Ok(())
+}
+
+#[cfg(not(ocvrs_has_image_pages))]
+#[test]
+fn pages_not_implemented() -> Result<()> {
+	let path = env::temp_dir().join("ocvrs_pages_not_implemented.tiff");
+	fs::write(&path, PAGES).unwrap();
+	assert!(matches!(imgcodecs::pages(&path), Err(e) if e.code == core::StsNotImplemented));
+	fs::remove_file(&path).ok();
+	Ok(())
+}
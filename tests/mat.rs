@@ -3,7 +3,7 @@ use std::ffi::c_void;
 use matches::assert_matches;
 
 use opencv::{
-	core::{self, MatConstIterator, Point, Rect, Scalar, Size, Vec2b, Vec3d, Vec3f, Vec4w},
+	core::{self, MatConstIterator, MatMut, MatRef, Point, Rect, Scalar, Size, Vec2b, Vec3d, Vec3f, Vec4w},
 	Error,
 	prelude::*,
 	Result,
@@ -543,3 +543,165 @@ fn mat_mul() -> Result<()> {
 	}
 	Ok(())
 }
+
+#[test]
+fn mat_ops() -> Result<()> {
+	let a = Mat::from_slice_2d(&[[10f64, 20.], [30., 40.]])?;
+	let b = Mat::from_slice_2d(&[[2f64, 4.], [6., 8.]])?;
+	let c = Mat::from_slice_2d(&[[1f64, 1.], [1., 1.]])?;
+	{
+		let res = ((&a - &b) * 0.5 + &c).into_mat()?;
+		assert_eq!(res.typ()?, f64::typ());
+		assert_eq!(res.data_typed::<f64>()?, &[5., 9., 13., 17.]);
+	}
+	{
+		let res = (-(&a / 2.) + Scalar::all(100.)).into_mat()?;
+		assert_eq!(res.data_typed::<f64>()?, &[95., 90., 85., 80.]);
+	}
+	{
+		let res = (&a * &c).into_mat()?;
+		assert_eq!(res.data_typed::<f64>()?, &[30., 30., 70., 70.]);
+	}
+	{
+		let m1 = Mat::from_slice(&[0b1100u8, 0b1010, 0xFF])?;
+		let m2 = Mat::from_slice(&[0b1010u8, 0b0110, 0x0F])?;
+		assert_eq!((&m1 & &m2).into_mat()?.data_typed::<u8>()?, &[0b1000, 0b0010, 0x0F]);
+		assert_eq!((&m1 | &m2).into_mat()?.data_typed::<u8>()?, &[0b1110, 0b1110, 0xFF]);
+		assert_eq!((&m1 ^ &m2).into_mat()?.data_typed::<u8>()?, &[0b0110, 0b1100, 0xF0]);
+		assert_eq!((!&m1).into_mat()?.data_typed::<u8>()?, &[0xF3, 0xF5, 0x00]);
+	}
+	{
+		let res = a.cmp_gt(&Scalar::all(25.)).into_mat()?;
+		assert_eq!(res.typ()?, u8::typ());
+		assert_eq!(res.data_typed::<u8>()?, &[0, 0, 255, 255]);
+		let res = (&a).cmp_eq(&(&b * 5.).into_result()?).into_mat()?;
+		assert_eq!(res.data_typed::<u8>()?, &[255, 255, 255, 255]);
+	}
+	{
+		let res = (Mat::default() + &a).into_mat();
+		assert_matches!(res, Err(Error { .. }));
+	}
+	Ok(())
+}
+
+#[test]
+fn mat_borrowed() -> Result<()> {
+	{
+		let data = [1u16, 2, 3, 4, 5, 6];
+		let mat = MatRef::new(&data, 2, 3)?;
+		assert_eq!(u16::typ(), mat.typ()?);
+		assert_eq!(Size::new(3, 2), mat.size()?);
+		assert_eq!(data.as_ptr() as *const u8, mat.data()? as *const u8);
+		assert_eq!(6, *mat.at_2d::<u16>(1, 2)?);
+		assert_eq!(Scalar::from(21.), core::sum_elems(&mat)?);
+		assert_matches!(MatRef::new(&data, 3, 3), Err(Error { code: core::StsUnmatchedSizes, .. }));
+		assert_matches!(MatRef::new(&data, -1, 3), Err(Error { code: core::StsOutOfRange, .. }));
+	}
+
+	{
+		let data = [1f32, 2., 0., 3., 4., 0., 5., 6.];
+		let mat = MatRef::new_with_stride(&data, 3, 2, 3)?;
+		assert!(!mat.is_continuous()?);
+		assert_eq!(Size::new(2, 3), mat.size()?);
+		assert_eq!(&[3., 4.], mat.at_row::<f32>(1)?);
+		assert_eq!(Scalar::from(21.), core::sum_elems(&mat)?);
+		assert_matches!(MatRef::new_with_stride(&data, 3, 2, 1), Err(Error { code: core::StsOutOfRange, .. }));
+		assert_matches!(MatRef::new_with_stride(&data, 3, 3, 3), Err(Error { code: core::StsUnmatchedSizes, .. }));
+		assert_matches!(MatRef::new_with_stride(&data, 3, 2, usize::MAX / 2 + 1), Err(Error { code: core::StsOutOfRange, .. }));
+		assert_matches!(MatRef::new_with_stride(&data, 1, 2, usize::MAX / 2), Err(Error { code: core::StsOutOfRange, .. }));
+	}
+
+	{
+		let src = [1u8, 2, 3, 4];
+		let mut dst = [0u8; 4];
+		{
+			let src = MatRef::new(&src, 2, 2)?;
+			let mut dst = MatMut::new(&mut dst, 2, 2)?;
+			core::flip(&src, &mut dst, 1)?;
+			*dst.at_2d_mut::<u8>(0, 0)? += 10;
+		}
+		assert_eq!([12, 1, 4, 3], dst);
+	}
+	Ok(())
+}
+
+#[test]
+fn mat_typed_iter() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[
+		[1i32, 2, 3, 4],
+		[5, 6, 7, 8],
+		[9, 10, 11, 12],
+	])?;
+	let mut roi = Mat::roi(&mat, Rect::new(1, 1, 2, 2))?.try_into_typed::<i32>()?;
+	assert!(!roi.is_continuous()?);
+	assert_eq!(vec![&[6, 7][..], &[10, 11][..]], roi.rows_iter()?.collect::<Vec<_>>());
+	assert_eq!(&[10, 11], roi.rows_iter()?.next_back().unwrap());
+	assert_eq!(4, roi.iter()?.len());
+	assert_eq!(vec![6, 7, 10, 11], roi.iter()?.copied().collect::<Vec<_>>());
+	assert_eq!(
+		vec![(Point::new(0, 0), &6), (Point::new(1, 0), &7), (Point::new(0, 1), &10), (Point::new(1, 1), &11)],
+		roi.indexed_iter()?.collect::<Vec<_>>(),
+	);
+	roi.iter_mut()?.for_each(|x| *x *= 10);
+	assert_eq!(70, *mat.at_2d::<i32>(1, 2)?);
+	assert_eq!(8, *mat.at_2d::<i32>(1, 3)?);
+	for row in roi.rows_iter_mut()? {
+		row[0] = 0;
+	}
+	assert_eq!(0, *mat.at_2d::<i32>(2, 1)?);
+	assert_eq!(110, *mat.at_2d::<i32>(2, 2)?);
+
+	let empty = Mat::default().try_into_typed::<u8>()?;
+	assert_eq!(0, empty.rows_iter()?.len());
+	assert_eq!(None, empty.iter()?.next());
+	Ok(())
+}
+
+#[test]
+fn mat_typed_index() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[
+		[1u16, 2, 3, 4],
+		[5, 6, 7, 8],
+		[9, 10, 11, 12],
+	])?.try_into_typed::<u16>()?;
+	assert_eq!(7, mat[(1, 2)]);
+	assert_eq!(7, mat[Point::new(2, 1)]);
+	mat[(0, 3)] = 40;
+	mat[Point::new(0, 2)] += 90;
+	assert_eq!(&[1, 2, 3, 40, 5, 6, 7, 8, 99, 10, 11, 12], mat.data_typed()?);
+
+	let mut roi = mat.roi(1.., 1..=2)?;
+	assert_eq!(Size::new(2, 2), roi.size()?);
+	assert_eq!(6, roi[(0, 0)]);
+	assert_eq!(11, roi[(1, 1)]);
+	roi[(1, 0)] = 100;
+	assert_eq!(100, mat[(2, 1)]);
+
+	let rows = mat.rows_in(..2)?;
+	assert_eq!(Size::new(4, 2), rows.size()?);
+	assert_eq!(40, rows[(0, 3)]);
+	let cols = mat.cols_in(3..)?;
+	assert_eq!(Size::new(1, 3), cols.size()?);
+	assert_eq!(12, cols[(2, 0)]);
+	assert_eq!(Size::new(4, 2), mat.row_range(&core::Range::new(0, 2)?)?.size()?);
+
+	assert_matches!(mat.roi(..4, ..), Err(Error { code: core::StsOutOfRange, .. }));
+	assert_matches!(mat.cols_in(3..2), Err(Error { code: core::StsOutOfRange, .. }));
+	assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| mat[(3, 0)])).is_err());
+	Ok(())
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn mat_par_rows_iter_mut() -> Result<()> {
+	use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+	let mut mat = Mat::new_rows_cols_with_default(100, 50, f32::typ(), Scalar::all(0.))?.try_into_typed::<f32>()?;
+	mat.par_rows_iter_mut()?
+		.enumerate()
+		.for_each(|(row_n, row)| row.iter_mut().for_each(|x| *x = row_n as f32));
+	assert_eq!(0., *mat.at_2d::<f32>(0, 49)?);
+	assert_eq!(42., *mat.at_2d::<f32>(42, 7)?);
+	assert_eq!(99., *mat.at_2d::<f32>(99, 0)?);
+	Ok(())
+}
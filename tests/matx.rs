@@ -1,7 +1,9 @@
+use std::convert::TryFrom;
+
 use matches::assert_matches;
 
 use opencv::{
-	core::{self, Matx22d, Matx23f, Matx32f, Matx33d, Matx66f, Point2f, Scalar},
+	core::{self, Mat, Matx, Matx22d, Matx23f, Matx32f, Matx33d, Matx66f, Point2f, Scalar},
 	imgproc,
 	prelude::*,
 	Result,
@@ -54,9 +56,9 @@ fn matx_arg() -> Result<()> {
 	use opencv::{core::Matx44d, surface_matching::Pose3D};
 
 	let mut pose = Pose3D::default()?;
-	assert!(&pose.pose().val.iter().all(|&x| x == 0.));
+	assert!(&pose.pose().val().iter().all(|&x| x == 0.));
 	pose.set_pose(Matx44d::all(9.));
-	assert!(&pose.pose().val.iter().all(|&x| x == 9.));
+	assert!(&pose.pose().val().iter().all(|&x| x == 9.));
 	Ok(())
 }
 
@@ -70,15 +72,15 @@ fn matx_input_array() -> Result<()> {
 #[test]
 fn matx_input_output_array() -> Result<()> {
 	let mut mat = Matx33d::from([
-		1., 2., 3.,
-		4., 5., 6.,
-		9., 8., 9.,
+		[1., 2., 3.],
+		[4., 5., 6.],
+		[9., 8., 9.],
 	]);
 	core::complete_symm(&mut mat, false)?;
 	let expected = Matx33d::from([
-		1., 2., 3.,
-		2., 5., 6.,
-		3., 6., 9.,
+		[1., 2., 3.],
+		[2., 5., 6.],
+		[3., 6., 9.],
 	]);
 	assert_eq!(expected, mat);
 	Ok(())
@@ -101,3 +103,64 @@ fn matx_all() -> Result<()> {
 	assert_eq!(mat[(3, 4)], 81.);
 	Ok(())
 }
+
+#[test]
+fn matx_arithmetic() -> Result<()> {
+	let a = Matx23f::from([
+		[1., 2., 3.],
+		[4., 5., 6.],
+	]);
+	let at = a.t();
+	assert_eq!(3, at.rows());
+	assert_eq!(2, at.cols());
+	assert_eq!(6., at[(2, 1)]);
+	let prod = a * at;
+	assert_eq!(Matx::<f32, 2, 2>::from([[14., 32.], [32., 77.]]), prod);
+	assert_eq!(Matx::<f32, 2, 2>::from([[7., 16.], [16., 38.5]]), 0.5 * prod);
+	assert_eq!(Matx::<f32, 2, 3>::from([[0., 0., 0.], [3., 3., 3.]]), a - Matx23f::from([[1., 2., 3.], [1., 2., 3.]]));
+	assert_eq!(91., a.dot(&a));
+
+	let big = Matx::<f64, 8, 5>::all(1.);
+	let prod = big * big.t();
+	assert_eq!(8, prod.rows());
+	assert_eq!(5., prod[(7, 7)]);
+	Ok(())
+}
+
+#[test]
+fn matx_inv_solve() -> Result<()> {
+	let a = Matx33d::from([
+		[2., 0., 0.],
+		[0., 4., 0.],
+		[1., 0., 1.],
+	]);
+	let inv = a.inv(core::DECOMP_LU)?;
+	assert_eq!(Matx33d::eye(), a * inv);
+	let rhs = Matx::<f64, 3, 1>::from([[2.], [8.], [3.]]);
+	assert_eq!(Matx::<f64, 3, 1>::from([[1.], [2.], [2.]]), a.solve(&rhs, core::DECOMP_LU)?);
+	assert!(Matx22d::zeros().inv(core::DECOMP_LU).is_err());
+
+	let proj = Matx::<f64, 3, 4>::from_slice(&[1., 0., 0., 10., 0., 1., 0., 20., 0., 0., 1., 30.])?;
+	assert_eq!(30., proj[(2, 3)]);
+	assert!(Matx::<f64, 3, 4>::from_slice(&[1., 2.]).is_err());
+	Ok(())
+}
+
+#[test]
+fn matx_mat_conversion() -> Result<()> {
+	let cov = Matx66f::eye() * 2.;
+	let mat = cov.to_mat()?;
+	assert_eq!(6, mat.rows());
+	assert_eq!(f32::typ(), mat.typ()?);
+	assert_eq!(2., *mat.at_2d::<f32>(5, 5)?);
+	assert_eq!(cov, Matx66f::try_from(&mat)?);
+	assert!(Matx33d::try_from(&mat).is_err());
+	assert!(Matx::<f32, 6, 5>::try_from(&mat).is_err());
+
+	let mut dst = Matx::<f64, 3, 4>::default();
+	let src = Mat::new_rows_cols_with_default(3, 4, f64::typ(), Scalar::all(7.))?;
+	src.copy_to(&mut dst)?;
+	assert_eq!(Matx::<f64, 3, 4>::all(7.), dst);
+	assert_eq!(Scalar::from(84.), core::sum_elems(&dst)?);
+	Ok(())
+}
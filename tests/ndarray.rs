@@ -0,0 +1,82 @@
+#![cfg(feature = "ndarray")]
+
+use std::convert::TryFrom;
+
+use matches::assert_matches;
+use ndarray::{array, ArrayViewD, Axis};
+
+use opencv::{
+	core::{self, Rect, Scalar, Vec3b},
+	Error,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn ndarray_view_channels() -> Result<()> {
+	let mat = Mat::new_rows_cols_with_default(4, 5, Vec3b::typ(), Scalar::new(1., 2., 3., 0.))?;
+	let view = mat.as_array_view::<u8>()?;
+	assert_eq!(&[4, 5, 3], view.shape());
+	assert_eq!(1, view[[0, 0, 0]]);
+	assert_eq!(2, view[[3, 4, 1]]);
+	assert_eq!(3, view[[2, 1, 2]]);
+	assert_matches!(mat.as_array_view::<u16>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	assert_matches!(mat.as_array_view::<Vec3b>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	Ok(())
+}
+
+#[test]
+fn ndarray_view_submatrix() -> Result<()> {
+	let mut mat = Mat::from_slice_2d(&[
+		[1u16, 2, 3, 4],
+		[5, 6, 7, 8],
+		[9, 10, 11, 12],
+	])?;
+	let mut roi = Mat::roi(&mat, Rect::new(1, 1, 2, 2))?;
+	assert!(!roi.is_continuous()?);
+	{
+		let view = roi.as_array_view::<u16>()?;
+		assert_eq!(&[2, 2, 1], view.shape());
+		assert_eq!(vec![6, 7, 10, 11], view.iter().copied().collect::<Vec<_>>());
+	}
+	roi.as_array_view_mut::<u16>()?.index_axis_mut(Axis(2), 0).fill(0);
+	assert_eq!(0, *mat.at_2d::<u16>(2, 2)?);
+	assert_eq!(12, *mat.at_2d::<u16>(2, 3)?);
+	*mat.at_2d_mut::<u16>(1, 1)? = 100;
+	assert_eq!(100, roi.as_array_view::<u16>()?[[0, 0, 0]]);
+	Ok(())
+}
+
+#[test]
+fn ndarray_view_typed() -> Result<()> {
+	let mat = Mat::new_rows_cols_with_default(2, 3, Vec3b::typ(), Scalar::new(10., 20., 30., 0.))?
+		.try_into_typed::<Vec3b>()?;
+	let view = ArrayViewD::<Vec3b>::try_from(&mat)?;
+	assert_eq!(&[2, 3], view.shape());
+	assert_eq!(Vec3b::from([10, 20, 30]), view[[1, 2]]);
+	let arr = mat.to_array()?;
+	assert_eq!(view, arr.view());
+	Ok(())
+}
+
+#[test]
+fn ndarray_from_array() -> Result<()> {
+	let arr = array![[1.5f32, 2.5, 3.5], [4.5, 5.5, 6.5]];
+	let mat = Mat::from_array(&arr)?;
+	assert_eq!(f32::typ(), mat.typ()?);
+	assert_eq!(2, mat.rows());
+	assert_eq!(3, mat.cols());
+	assert_eq!(6.5, *mat.at_2d::<f32>(1, 2)?);
+
+	let mat = Mat::from_array(&arr.t())?;
+	assert_eq!(3, mat.rows());
+	assert_eq!(2, mat.cols());
+	assert_eq!(4.5, *mat.at_2d::<f32>(0, 1)?);
+
+	let arr = array![[[1u8, 2, 3], [4, 5, 6]]];
+	let mat = Mat::from_array_with_channels(&arr)?;
+	assert_eq!(Vec3b::typ(), mat.typ()?);
+	assert_eq!(Vec3b::from([4, 5, 6]), *mat.at_2d::<Vec3b>(0, 1)?);
+	assert_eq!(arr.into_dyn(), mat.to_array::<u8>()?);
+	Ok(())
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "serde")]
+
+use opencv::{
+	core::{self, DMatch, KeyPoint, Mat, Matx33d, Point, Point2f, Point3d, Rect, RotatedRect, Scalar, Size, Size2f, TermCriteria, Vec3b},
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn serde_simple_types() -> Result<()> {
+	let pt = Point::new(10, -20);
+	assert_eq!(r#"{"x":10,"y":-20}"#, serde_json::to_string(&pt).unwrap());
+	assert_eq!(pt, serde_json::from_str(r#"{"x":10,"y":-20}"#).unwrap());
+
+	let pt3 = Point3d::new(1.5, 2., 3.);
+	assert_eq!(pt3, serde_json::from_str::<Point3d>(&serde_json::to_string(&pt3).unwrap()).unwrap());
+
+	let rect = Rect::new(1, 2, 3, 4);
+	assert_eq!(r#"{"x":1,"y":2,"width":3,"height":4}"#, serde_json::to_string(&rect).unwrap());
+	assert_eq!(Size::new(3, 4), serde_json::from_str(r#"{"width":3,"height":4}"#).unwrap());
+
+	assert_eq!("[1,2,3]", serde_json::to_string(&Vec3b::from([1, 2, 3])).unwrap());
+	assert_eq!(Scalar::new(1., 2., 3., 4.), serde_json::from_str("[1,2,3,4]").unwrap());
+
+	let matx = Matx33d::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+	assert_eq!("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]", serde_json::to_string(&matx).unwrap());
+	assert_eq!(matx, serde_json::from_str("[1,2,3,4,5,6,7,8,9]").unwrap());
+	assert!(serde_json::from_str::<Matx33d>("[1,2,3]").is_err());
+	Ok(())
+}
+
+#[test]
+fn serde_bound_types() -> Result<()> {
+	let kp = KeyPoint::new_point(Point2f::new(10., 20.), 5., 90., 0.5, 1, -1)?;
+	let kp_back: KeyPoint = serde_json::from_str(&serde_json::to_string(&kp).unwrap()).unwrap();
+	assert_eq!(kp, kp_back);
+
+	let dm = DMatch::new(1, 2, 0.5)?;
+	assert_eq!(r#"{"query_idx":1,"train_idx":2,"img_idx":-1,"distance":0.5}"#, serde_json::to_string(&dm).unwrap());
+	assert_eq!(dm, serde_json::from_str(&serde_json::to_string(&dm).unwrap()).unwrap());
+
+	let tc = TermCriteria::new(core::TermCriteria_Type::COUNT as i32, 30, 0.1)?;
+	assert_eq!(tc, serde_json::from_str(&serde_json::to_string(&tc).unwrap()).unwrap());
+
+	let rr = RotatedRect::new(Point2f::new(5., 6.), Size2f::new(10., 20.), 45.)?;
+	let rr_back: RotatedRect = serde_json::from_str(&serde_json::to_string(&rr).unwrap()).unwrap();
+	assert_eq!(rr.center(), rr_back.center());
+	assert_eq!(rr.size(), rr_back.size());
+	assert_eq!(rr.angle(), rr_back.angle());
+	Ok(())
+}
+
+#[test]
+fn serde_mat() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[[1u16, 2, 3], [4, 5, 6]])?;
+	let json = serde_json::to_string(&mat).unwrap();
+	assert_eq!(format!(r#"{{"typ":{},"dims":[2,3],"data":[1,0,2,0,3,0,4,0,5,0,6,0]}}"#, u16::typ()), json);
+	let mat_back: Mat = serde_json::from_str(&json).unwrap();
+	assert_eq!(mat.typ()?, mat_back.typ()?);
+	assert_eq!(mat.data_typed::<u16>()?, mat_back.data_typed::<u16>()?);
+
+	let roi = Mat::roi(&mat, Rect::new(1, 0, 2, 2))?;
+	let roi_back: Mat = serde_json::from_str(&serde_json::to_string(&roi).unwrap()).unwrap();
+	assert_eq!(&[2, 3, 5, 6], roi_back.data_typed::<u16>()?);
+
+	let empty: Mat = serde_json::from_str(&serde_json::to_string(&Mat::default()).unwrap()).unwrap();
+	assert!(empty.empty()?);
+
+	let typed = mat.try_into_typed::<u16>()?;
+	let typed_back: core::Mat_<u16> = serde_json::from_str(&serde_json::to_string(&typed).unwrap()).unwrap();
+	assert_eq!(typed.data_typed()?, typed_back.data_typed()?);
+	assert!(serde_json::from_str::<core::Mat_<f32>>(&json).is_err());
+	assert!(serde_json::from_str::<Mat>(r#"{"typ":0,"dims":[2,2],"data":[1,2,3]}"#).is_err());
+	Ok(())
+}
+
+#[cfg(ocvrs_has_module_calib3d)]
+#[test]
+fn serde_calibration() -> Result<()> {
+	use opencv::calib3d::{CameraIntrinsics, DistortionModel};
+
+	let intrinsics = CameraIntrinsics {
+		camera_matrix: Matx33d::from([[800., 0., 320.], [0., 800., 240.], [0., 0., 1.]]),
+		dist_coeffs: DistortionModel::Fisheye([0.1, 0.01, 0., 0.]),
+		image_size: Size::new(640, 480),
+	};
+	let json = serde_json::to_string(&intrinsics).unwrap();
+	assert!(json.contains(r#""dist_coeffs":{"Fisheye":[0.1,0.01,0.0,0.0]}"#));
+	assert_eq!(intrinsics, serde_json::from_str(&json).unwrap());
+	Ok(())
+}
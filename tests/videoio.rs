@@ -0,0 +1,216 @@
+#![cfg(all(ocvrs_has_module_videoio, not(ocvrs_opencv_branch_32)))]
+
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use opencv::{
+	core::{Mat, Scalar, Size, Vec3b},
+	prelude::*,
+	Result,
+	videoio::{self, AsyncVideoCapture, CaptureMode, CaptureRequirements, FourCC, OverflowPolicy, VideoCapture, VideoWriter},
+};
+
+fn write_test_video(name: &str, frames: i32) -> Result<PathBuf> {
+	let path = env::temp_dir().join(name);
+	let mut writer = VideoWriter::new_with_backend(path.to_str().unwrap(), videoio::CAP_OPENCV_MJPEG, FourCC::new(*b"MJPG").code(), 10., Size::new(32, 24), true)?;
+	assert!(writer.is_opened()?);
+	assert_eq!(videoio::VideoCaptureAPIs::CAP_OPENCV_MJPEG, writer.backend()?);
+	for i in 0..frames {
+		let frame = Mat::new_rows_cols_with_default(24, 32, Vec3b::typ(), Scalar::all(f64::from(i * 20)))?;
+		writer.write(&frame)?;
+	}
+	writer.release()?;
+	Ok(path)
+}
+
+#[test]
+fn capture_frames() -> Result<()> {
+	let path = write_test_video("ocvrs_capture_frames.avi", 5)?;
+	let mut cap = VideoCapture::from_file(path.to_str().unwrap(), videoio::CAP_OPENCV_MJPEG)?;
+	assert!(cap.is_opened()?);
+	let frames = cap.frames().collect::<Result<Vec<_>>>()?;
+	assert_eq!(5, frames.len());
+	for (i, frame) in frames.iter().enumerate() {
+		assert_eq!(i as u64, frame.index);
+		assert_eq!(Size::new(32, 24), frame.mat.size()?);
+	}
+	assert!(frames.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+	assert!(cap.frames().next().is_none());
+
+	let mut cap = VideoCapture::from_file(path.to_str().unwrap(), videoio::CAP_OPENCV_MJPEG)?;
+	let mut buffer = Mat::default();
+	let mut count = 0;
+	for frame in cap.frames_into(&mut buffer) {
+		let frame = frame?;
+		assert_eq!(count, frame.index);
+		assert_eq!(Size::new(32, 24), frame.mat.size()?);
+		count += 1;
+	}
+	assert_eq!(5, count);
+	assert_eq!(Size::new(32, 24), buffer.size()?);
+	fs::remove_file(path).ok();
+	Ok(())
+}
+
+#[test]
+fn fourcc() -> Result<()> {
+	let fourcc: FourCC = "MJPG".parse()?;
+	assert_eq!(VideoWriter::fourcc('M' as i8, 'J' as i8, 'P' as i8, 'G' as i8)?, fourcc.code());
+	assert_eq!(fourcc, FourCC::from_code(fourcc.code()));
+	assert_eq!("MJPG", fourcc.to_string());
+	assert_eq!(*b"YUYV", FourCC::new(*b"YUYV").chars());
+	assert!("MJP".parse::<FourCC>().is_err());
+	assert!("MJPGG".parse::<FourCC>().is_err());
+	Ok(())
+}
+
+#[test]
+fn capture_properties() -> Result<()> {
+	let path = write_test_video("ocvrs_capture_properties.avi", 3)?;
+	let cap = VideoCapture::from_file(path.to_str().unwrap(), videoio::CAP_OPENCV_MJPEG)?;
+	assert!(cap.is_opened()?);
+	assert_eq!(10., cap.fps()?);
+	assert_eq!(Size::new(32, 24), cap.frame_size()?);
+	assert_eq!(FourCC::new(*b"MJPG"), cap.fourcc()?);
+	assert_eq!(Duration::default(), cap.position()?);
+	assert_eq!(videoio::VideoCaptureAPIs::CAP_OPENCV_MJPEG, cap.backend()?);
+	fs::remove_file(path).ok();
+	Ok(())
+}
+
+#[test]
+fn async_capture() -> Result<()> {
+	let path = write_test_video("ocvrs_async_capture.avi", 10)?;
+
+	let cap = VideoCapture::from_file(path.to_str().unwrap(), videoio::CAP_OPENCV_MJPEG)?;
+	let cap = AsyncVideoCapture::new(cap, 2, OverflowPolicy::Block)?;
+	let mut indices = vec![];
+	while let Some(frame) = cap.next_frame() {
+		indices.push(frame?.index);
+	}
+	assert_eq!((0..10).collect::<Vec<_>>(), indices);
+	assert_eq!(0, cap.dropped_frames());
+	assert!(cap.into_inner()?.is_opened()?);
+
+	let cap = VideoCapture::from_file(path.to_str().unwrap(), videoio::CAP_OPENCV_MJPEG)?;
+	let cap = AsyncVideoCapture::new(cap, 1, OverflowPolicy::DropOldest)?;
+	let mut last_index = None;
+	while let Some(frame) = cap.latest() {
+		let frame = frame?;
+		assert!(last_index.map_or(true, |last_index| last_index < frame.index));
+		last_index = Some(frame.index);
+	}
+	assert_eq!(Some(9), last_index);
+
+	assert!(AsyncVideoCapture::new(VideoCapture::default()?, 0, OverflowPolicy::Block).is_err());
+	fs::remove_file(path).ok();
+	Ok(())
+}
+
+#[cfg(feature = "futures-core")]
+#[test]
+fn async_capture_stream() -> Result<()> {
+	use futures::{executor::block_on, StreamExt};
+
+	let path = write_test_video("ocvrs_async_capture_stream.avi", 5)?;
+	let cap = VideoCapture::from_file(path.to_str().unwrap(), videoio::CAP_OPENCV_MJPEG)?;
+	let cap = AsyncVideoCapture::new(cap, 2, OverflowPolicy::Block)?;
+	let frames = block_on(cap.collect::<Vec<_>>())
+		.into_iter()
+		.collect::<Result<Vec<_>>>()?;
+	assert_eq!(5, frames.len());
+	fs::remove_file(path).ok();
+	Ok(())
+}
+
+#[test]
+fn writer_builder() -> Result<()> {
+	let path = env::temp_dir().join("ocvrs_writer_builder.avi");
+	let mut writer = VideoWriter::builder(&path)
+		.fourcc(FourCC::new(*b"MJPG"))
+		.fps(25.)
+		.frame_size(Size::new(32, 24))
+		.backend(videoio::VideoCaptureAPIs::CAP_OPENCV_MJPEG)
+		.build()?;
+	assert!(writer.is_opened()?);
+	writer.write(&Mat::new_rows_cols_with_default(24, 32, Vec3b::typ(), Scalar::all(128.))?)?;
+	writer.release()?;
+	assert!(fs::metadata(&path).unwrap().len() > 0);
+	fs::remove_file(&path).ok();
+
+	let err = VideoWriter::builder(&path)
+		.fps(25.)
+		.frame_size(Size::new(32, 24))
+		.build()
+		.unwrap_err();
+	assert!(err.message.contains("FourCC"));
+
+	let err = VideoWriter::builder(&path)
+		.fourcc(FourCC::new(*b"MJPG"))
+		.fps(-1.)
+		.frame_size(Size::new(32, 24))
+		.build()
+		.unwrap_err();
+	assert!(err.message.contains("Frame rate"));
+
+	let err = VideoWriter::builder(env::temp_dir().join("ocvrs_non_existent_dir").join("out.avi"))
+		.fourcc(FourCC::new(*b"MJPG"))
+		.fps(25.)
+		.frame_size(Size::new(32, 24))
+		.build()
+		.unwrap_err();
+	assert!(err.message.contains("directory"));
+
+	let err = VideoWriter::builder(env::temp_dir().join("ocvrs_writer_builder.unknown_container"))
+		.fourcc(FourCC::new(*b"XXXX"))
+		.fps(25.)
+		.frame_size(Size::new(32, 24))
+		.backend(videoio::VideoCaptureAPIs::CAP_OPENCV_MJPEG)
+		.build()
+		.unwrap_err();
+	assert!(err.message.contains("tried backends"));
+	assert!(!env::temp_dir().join("ocvrs_writer_builder.unknown_container").exists());
+	Ok(())
+}
+
+#[test]
+fn devices() -> Result<()> {
+	// machines running the tests usually have no cameras, so only the consistency of the results can be checked
+	for device in videoio::list_devices()? {
+		assert!(device.name.contains(&format!("#{}", device.index)));
+		assert!(!device.modes.is_empty());
+	}
+	let requirements = CaptureRequirements {
+		min_frame_size: Some(Size::new(100_000, 100_000)),
+		..CaptureRequirements::default()
+	};
+	assert!(VideoCapture::open_best(&requirements).is_err());
+	Ok(())
+}
+
+#[test]
+fn capture_requirements() {
+	let mjpg_vga = CaptureMode { fourcc: FourCC::new(*b"MJPG"), frame_size: Size::new(640, 480) };
+	let mjpg_hd = CaptureMode { fourcc: FourCC::new(*b"MJPG"), frame_size: Size::new(1280, 720) };
+	let yuyv_fhd = CaptureMode { fourcc: FourCC::new(*b"YUYV"), frame_size: Size::new(1920, 1080) };
+
+	let any = CaptureRequirements::default();
+	assert!(any.accepts(&mjpg_vga));
+	assert!(any.accepts(&yuyv_fhd));
+	// the largest frame size is preferred by default
+	assert!(any.score(&yuyv_fhd) < any.score(&mjpg_hd));
+	assert!(any.score(&mjpg_hd) < any.score(&mjpg_vga));
+
+	let mjpg = CaptureRequirements { fourcc: Some(FourCC::new(*b"MJPG")), ..CaptureRequirements::default() };
+	assert!(mjpg.accepts(&mjpg_vga));
+	assert!(!mjpg.accepts(&yuyv_fhd));
+
+	let min_size = CaptureRequirements { min_frame_size: Some(Size::new(1280, 480)), ..CaptureRequirements::default() };
+	assert!(!min_size.accepts(&mjpg_vga));
+	assert!(min_size.accepts(&mjpg_hd));
+	assert!(min_size.accepts(&yuyv_fhd));
+
+	let preferred = CaptureRequirements { preferred_frame_size: Some(Size::new(1280, 720)), ..CaptureRequirements::default() };
+	assert_eq!(0, preferred.score(&mjpg_hd));
+	assert!(preferred.score(&mjpg_hd) < preferred.score(&mjpg_vga));
+	assert!(preferred.score(&mjpg_hd) < preferred.score(&yuyv_fhd));
+}